@@ -0,0 +1,106 @@
+use swc_core::common::errors::{DiagnosticId, Handler};
+use swc_core::common::Span;
+use swc_core::{common, plugin};
+
+/// Runs `f` with the plugin host's handler, or with the scoped handler outside of a plugin host, such as
+/// in tests.
+pub fn with_handler<R>(f: impl FnOnce(&Handler) -> R) -> R {
+	match plugin::errors::HANDLER.inner.get() {
+		Some(handler) => f(handler),
+		None => common::errors::HANDLER.with(f),
+	}
+}
+
+/// An error reported by the transform.
+///
+/// Each error has a stable code, so that it can be looked up in the README or matched by tooling.
+pub enum Error {
+	/// The plugin config could not be deserialized.
+	InvalidConfig { message: String },
+	/// `importSource` was specified, but `template` is not an identifier that can be imported.
+	ImportSourceWithoutIdent,
+	/// An element has both `dangerouslySetInnerHTML` or `innerHTML` and children.
+	InnerHtmlWithChildren { attr: Span, children: Span },
+	/// A void element such as `<input>` has `dangerouslySetInnerHTML` or `innerHTML`.
+	InnerHtmlOnVoidElement { attr: Span, name: String },
+	/// A pragma comment such as `@jsxStatic` has a missing or invalid value.
+	InvalidPragma { span: Span, message: String },
+	/// A namespaced attribute on an SVG or MathML element is not a known `xlink:`, `xml:` or `xmlns:`
+	/// attribute.
+	UnknownNamespacedAttribute { span: Span, name: String },
+	/// An attribute name is the same as a configured hole key, such as `$$child`.
+	ReservedAttributeName { span: Span, name: String },
+}
+
+impl Error {
+	pub fn code(&self) -> &'static str {
+		match self {
+			Error::InvalidConfig { .. } => "SJ001",
+			Error::ImportSourceWithoutIdent => "SJ002",
+			Error::InnerHtmlWithChildren { .. } => "SJ003",
+			Error::InvalidPragma { .. } => "SJ004",
+			Error::UnknownNamespacedAttribute { .. } => "SJ005",
+			Error::ReservedAttributeName { .. } => "SJ006",
+			Error::InnerHtmlOnVoidElement { .. } => "SJ007",
+		}
+	}
+
+	pub fn emit(self) {
+		let code = DiagnosticId::Error(self.code().into());
+		with_handler(|handler| match self {
+			Error::InvalidConfig { message } => handler
+				.struct_err_with_code("[swc-plugin-static-jsx] failed to parse config", code)
+				.note(&message)
+				.emit(),
+			Error::ImportSourceWithoutIdent => handler
+				.struct_err_with_code("[swc-plugin-static-jsx] incompatible template function", code)
+				.note("expected `template` to be an identifier because `importSource` was specified")
+				.help("set `template` to the name of the export, such as \"html\"")
+				.emit(),
+			Error::InnerHtmlWithChildren { attr, children } => handler
+				.struct_span_err_with_code(
+					attr,
+					"[swc-plugin-static-jsx] inner HTML cannot be combined with children",
+					code,
+				)
+				.span_label(attr, "inner HTML is set here")
+				.span_label(children, "children are ignored")
+				.help("remove either the attribute or the children")
+				.emit(),
+			Error::InnerHtmlOnVoidElement { attr, name } => handler
+				.struct_span_err_with_code(
+					attr,
+					&format!("[swc-plugin-static-jsx] inner HTML cannot be set on the void element `{name}`"),
+					code,
+				)
+				.span_label(attr, "inner HTML is set here")
+				.help("void elements cannot have children, so remove the attribute")
+				.emit(),
+			Error::InvalidPragma { span, message } => handler
+				.struct_span_err_with_code(span, "[swc-plugin-static-jsx] invalid pragma", code)
+				.span_label(span, message)
+				.emit(),
+			Error::UnknownNamespacedAttribute { span, name } => handler
+				.struct_span_err_with_code(
+					span,
+					&format!("[swc-plugin-static-jsx] unknown namespaced attribute `{name}`"),
+					code,
+				)
+				.span_label(span, "not a known SVG or MathML attribute")
+				.help(concat!(
+					"known namespaced attributes are the XLink attributes such as `xlink:href`, ",
+					"`xml:base`, `xml:lang`, `xml:space` and `xmlns:xlink`",
+				))
+				.emit(),
+			Error::ReservedAttributeName { span, name } => handler
+				.struct_span_err_with_code(
+					span,
+					&format!("[swc-plugin-static-jsx] attribute name `{name}` is reserved"),
+					code,
+				)
+				.span_label(span, "this would be mistaken for a hole")
+				.help("rename the attribute, or configure a different key for the hole")
+				.emit(),
+		})
+	}
+}
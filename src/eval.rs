@@ -0,0 +1,236 @@
+use swc_core::common::collections::{AHashMap, AHashSet};
+use swc_core::common::{Mark, SyntaxContext, DUMMY_SP};
+use swc_core::ecma::ast::*;
+use swc_core::ecma::utils::{find_pat_ids, ExprCtx, ExprExt};
+use swc_core::ecma::visit::{noop_visit_type, Visit, VisitWith};
+
+use crate::transform::{unwrap_expr, JsNumber};
+
+/// Evaluates expressions whose value is known at compile time, including references to `const`
+/// bindings initialized with a string or number literal.
+pub struct Evaluator {
+	ctx: ExprCtx,
+	constants: AHashMap<Id, Box<Expr>>,
+}
+
+impl Default for Evaluator {
+	fn default() -> Self {
+		Self::new(SyntaxContext::empty())
+	}
+}
+
+impl Evaluator {
+	fn new(unresolved_ctxt: SyntaxContext) -> Self {
+		Self {
+			ctx: ExprCtx {
+				unresolved_ctxt,
+				is_unresolved_ref_safe: false,
+			},
+			constants: Default::default(),
+		}
+	}
+
+	pub fn with_unresolved_mark(unresolved_mark: Mark) -> Self {
+		Self::new(SyntaxContext::empty().apply_mark(unresolved_mark))
+	}
+
+	/// Collects constant bindings from a module or script that has already gone through the resolver.
+	pub fn collect_constants<N>(&mut self, n: &N)
+	where
+		N: VisitWith<ConstantsCollector>,
+	{
+		let mut collector = ConstantsCollector::default();
+		n.visit_with(&mut collector);
+		let ConstantsCollector {
+			mut bindings,
+			reassigned,
+		} = collector;
+		bindings.retain(|id, _| !reassigned.contains(id));
+		self.constants = bindings;
+	}
+
+	/// Replaces `expr` with a literal if its value is known at compile time.
+	pub fn fold(&self, expr: &mut Expr) {
+		if let Expr::Lit(..) = expr {
+			return;
+		}
+		if let Some(value) = self.eval(expr) {
+			*expr = value;
+		}
+	}
+
+	/// Returns the value of `expr` as a string, number, boolean, `null` or `void 0` literal.
+	fn eval(&self, expr: &Expr) -> Option<Expr> {
+		let expr = unwrap_expr(expr);
+		let value = match expr {
+			Expr::Lit(Lit::Str(..) | Lit::Num(..) | Lit::Bool(..) | Lit::Null(..)) => expr.clone(),
+			Expr::Ident(ident) => match self.constants.get(&ident.to_id()) {
+				Some(value) => return self.eval(value),
+				None if &*ident.sym == "undefined" && ident.span.ctxt == self.ctx.unresolved_ctxt => undefined(),
+				None => return None,
+			},
+			Expr::Tpl(Tpl { exprs, quasis, .. }) => {
+				let mut value = String::new();
+				for (idx, quasi) in quasis.iter().enumerate() {
+					value.push_str(quasi.cooked.as_deref()?);
+					if let Some(expr) = exprs.get(idx) {
+						value.push_str(&self.to_string(&self.eval(expr)?)?);
+					}
+				}
+				str(value)
+			}
+			Expr::Unary(UnaryExpr { op, arg, .. }) => {
+				let arg = self.eval(arg)?;
+				match op {
+					UnaryOp::Minus => num(-arg.as_pure_number(&self.ctx).into_result().ok()?),
+					UnaryOp::Plus => num(arg.as_pure_number(&self.ctx).into_result().ok()?),
+					UnaryOp::Bang => bool(!arg.as_pure_bool(&self.ctx).into_result().ok()?),
+					UnaryOp::Void => undefined(),
+					_ => return None,
+				}
+			}
+			Expr::Bin(BinExpr { op, left, right, .. }) => {
+				let left = self.eval(left)?;
+				match op {
+					BinaryOp::LogicalAnd | BinaryOp::LogicalOr => {
+						let truthy = left.as_pure_bool(&self.ctx).into_result().ok()?;
+						return if truthy == (*op == BinaryOp::LogicalOr) {
+							Some(left)
+						} else {
+							self.eval(right)
+						};
+					}
+					BinaryOp::NullishCoalescing => {
+						return match left {
+							Expr::Lit(Lit::Null(..)) | Expr::Unary(..) => self.eval(right),
+							_ => Some(left),
+						};
+					}
+					_ => {}
+				}
+				let right = self.eval(right)?;
+				let is_str = |expr: &Expr| matches!(expr, Expr::Lit(Lit::Str(..)));
+				if *op == BinaryOp::Add && (is_str(&left) || is_str(&right)) {
+					let left = self.to_string(&left)?;
+					let right = self.to_string(&right)?;
+					return Some(str(format!("{left}{right}")));
+				}
+				if matches!(op, BinaryOp::Lt | BinaryOp::LtEq | BinaryOp::Gt | BinaryOp::GtEq) {
+					// Strings are compared by UTF-16 code units, and a string compared with anything else
+					// depends on how it converts to a number, so leave that to runtime.
+					if let (Expr::Lit(Lit::Str(left)), Expr::Lit(Lit::Str(right))) = (&left, &right) {
+						let ordering = left.value.encode_utf16().cmp(right.value.encode_utf16());
+						return Some(bool(match op {
+							BinaryOp::Lt => ordering.is_lt(),
+							BinaryOp::LtEq => ordering.is_le(),
+							BinaryOp::Gt => ordering.is_gt(),
+							_ => ordering.is_ge(),
+						}));
+					}
+					if is_str(&left) || is_str(&right) {
+						return None;
+					}
+				}
+				let left = left.as_pure_number(&self.ctx).into_result().ok()?;
+				let right = right.as_pure_number(&self.ctx).into_result().ok()?;
+				match op {
+					BinaryOp::Lt => return Some(bool(left < right)),
+					BinaryOp::LtEq => return Some(bool(left <= right)),
+					BinaryOp::Gt => return Some(bool(left > right)),
+					BinaryOp::GtEq => return Some(bool(left >= right)),
+					_ => {}
+				}
+				num(match op {
+					BinaryOp::Add => left + right,
+					BinaryOp::Sub => left - right,
+					BinaryOp::Mul => left * right,
+					BinaryOp::Div => left / right,
+					BinaryOp::Mod => left % right,
+					_ => return None,
+				})
+			}
+			Expr::Cond(CondExpr { test, cons, alt, .. }) => {
+				let test = self.eval(test)?.as_pure_bool(&self.ctx).into_result().ok()?;
+				return self.eval(if test { cons } else { alt });
+			}
+			_ => return None,
+		};
+		Some(value)
+	}
+
+	/// Converts a value returned by [`Self::eval`] to a string, formatting numbers like JavaScript.
+	fn to_string(&self, value: &Expr) -> Option<String> {
+		match value {
+			Expr::Lit(Lit::Num(Number { value, .. })) => Some(JsNumber(*value).to_string()),
+			_ => Some(value.as_pure_string(&self.ctx).into_result().ok()?.into_owned()),
+		}
+	}
+}
+
+fn str(value: String) -> Expr {
+	Expr::Lit(Lit::Str(Str {
+		span: DUMMY_SP,
+		value: value.into(),
+		raw: None,
+	}))
+}
+
+fn num(value: f64) -> Expr {
+	Expr::Lit(Lit::Num(value.into()))
+}
+
+fn bool(value: bool) -> Expr {
+	Expr::Lit(Lit::Bool(Bool { span: DUMMY_SP, value }))
+}
+
+fn undefined() -> Expr {
+	Expr::Unary(UnaryExpr {
+		span: DUMMY_SP,
+		op: UnaryOp::Void,
+		arg: Box::new(num(0.0)),
+	})
+}
+
+#[derive(Default)]
+pub struct ConstantsCollector {
+	bindings: AHashMap<Id, Box<Expr>>,
+	reassigned: AHashSet<Id>,
+}
+
+impl Visit for ConstantsCollector {
+	noop_visit_type!();
+	fn visit_var_decl(&mut self, n: &VarDecl) {
+		n.visit_children_with(self);
+		if n.kind != VarDeclKind::Const {
+			return;
+		}
+		for decl in &n.decls {
+			let (Pat::Ident(BindingIdent { id, .. }), Some(init)) = (&decl.name, &decl.init) else {
+				continue;
+			};
+			match unwrap_expr(init) {
+				Expr::Lit(Lit::Str(..) | Lit::Num(..)) => {}
+				Expr::Tpl(tpl) if tpl.exprs.is_empty() => {}
+				_ => continue,
+			}
+			self.bindings.insert(id.to_id(), init.clone());
+		}
+	}
+	fn visit_assign_expr(&mut self, n: &AssignExpr) {
+		n.visit_children_with(self);
+		match &n.left {
+			PatOrExpr::Pat(pat) => self.reassigned.extend(find_pat_ids::<_, Id>(pat)),
+			PatOrExpr::Expr(expr) => {
+				if let Expr::Ident(ident) = expr.as_ref() {
+					self.reassigned.insert(ident.to_id());
+				}
+			}
+		}
+	}
+	fn visit_update_expr(&mut self, n: &UpdateExpr) {
+		n.visit_children_with(self);
+		if let Expr::Ident(ident) = n.arg.as_ref() {
+			self.reassigned.insert(ident.to_id());
+		}
+	}
+}
@@ -0,0 +1,68 @@
+use swc_core::common::comments::Comment;
+use swc_core::common::DUMMY_SP;
+use swc_core::ecma::ast::{Expr, Str};
+
+use crate::diagnostics::Error;
+use crate::transform::parse_expr;
+
+/// Per-file overrides of the plugin config, read from comments such as `/** @jsxTemplate html */`.
+#[derive(Default)]
+pub struct Pragmas {
+	/// `@jsxTemplate <expr>`
+	pub template: Option<Box<Expr>>,
+	/// `@jsxTemplateImportSource <source>`
+	pub import_source: Option<Str>,
+	/// `@jsxStatic off`
+	pub disabled: bool,
+}
+
+impl Pragmas {
+	pub fn parse(comments: &[Comment]) -> Self {
+		let mut pragmas = Self::default();
+		for comment in comments {
+			let mut words = comment
+				.text
+				.split_whitespace()
+				.map(|word| word.trim_start_matches('*'))
+				.filter(|word| !word.is_empty());
+			while let Some(word) = words.next() {
+				let value = match word {
+					"@jsxTemplate" | "@jsxTemplateImportSource" | "@jsxStatic" => words.next(),
+					_ => continue,
+				};
+				let Some(value) = value else {
+					Error::InvalidPragma {
+						span: comment.span,
+						message: format!("expected a value after `{word}`"),
+					}
+					.emit();
+					continue;
+				};
+				match word {
+					"@jsxTemplate" => {
+						if let Ok(template) = parse_expr::<serde::de::value::Error>(value) {
+							pragmas.template = Some(template);
+						}
+					}
+					"@jsxTemplateImportSource" => {
+						pragmas.import_source = Some(Str {
+							span: DUMMY_SP,
+							value: value.into(),
+							raw: None,
+						})
+					}
+					_ => match value {
+						"on" => pragmas.disabled = false,
+						"off" => pragmas.disabled = true,
+						_ => Error::InvalidPragma {
+							span: comment.span,
+							message: format!("expected `on` or `off` after `@jsxStatic`, found `{value}`"),
+						}
+						.emit(),
+					},
+				}
+			}
+		}
+		pragmas
+	}
+}
@@ -0,0 +1,134 @@
+use std::fmt::Write;
+use swc_core::ecma::ast::*;
+
+use crate::eval::Evaluator;
+use crate::transform::{static_value, JsNumber, StaticValue};
+
+/// CSS properties that React does not suffix with `px` when given a number.
+static UNITLESS_PROPERTIES: phf::Set<&str> = phf::phf_set!(
+	"animationIterationCount",
+	"aspectRatio",
+	"borderImageOutset",
+	"borderImageSlice",
+	"borderImageWidth",
+	"boxFlex",
+	"boxFlexGroup",
+	"boxOrdinalGroup",
+	"columnCount",
+	"columns",
+	"flex",
+	"flexGrow",
+	"flexPositive",
+	"flexShrink",
+	"flexNegative",
+	"flexOrder",
+	"gridArea",
+	"gridRow",
+	"gridRowEnd",
+	"gridRowSpan",
+	"gridRowStart",
+	"gridColumn",
+	"gridColumnEnd",
+	"gridColumnSpan",
+	"gridColumnStart",
+	"fontWeight",
+	"lineClamp",
+	"lineHeight",
+	"opacity",
+	"order",
+	"orphans",
+	"scale",
+	"tabSize",
+	"widows",
+	"zIndex",
+	"zoom",
+	"fillOpacity",
+	"floodOpacity",
+	"stopOpacity",
+	"strokeDasharray",
+	"strokeDashoffset",
+	"strokeMiterlimit",
+	"strokeOpacity",
+	"strokeWidth"
+);
+
+/// Serializes the leading entries of a `style` object whose values are literals, removing them from
+/// the object. Entries after the first dynamic one are kept as-is so that later declarations can
+/// still override earlier ones.
+pub fn extract_static_style(obj: &mut ObjectLit, evaluator: &Evaluator) -> String {
+	let mut css = String::new();
+	let mut count = 0;
+	for prop in &mut obj.props {
+		let PropOrSpread::Prop(prop) = prop else { break };
+		let Prop::KeyValue(KeyValueProp { key, value }) = prop.as_mut() else {
+			break;
+		};
+		evaluator.fold(value);
+		let name = match key {
+			PropName::Ident(ident) => ident.sym.as_ref(),
+			PropName::Str(str) => str.value.as_ref(),
+			_ => break,
+		};
+		let value = match value.as_ref() {
+			Expr::Lit(Lit::Num(Number { value, .. })) => {
+				if *value == 0.0 || name.starts_with("--") || is_unitless(name) {
+					JsNumber(*value).to_string()
+				} else {
+					format!("{}px", JsNumber(*value))
+				}
+			}
+			other => match static_value(other) {
+				Some(StaticValue::Str(value)) => value.trim().to_string(),
+				Some(StaticValue::Omit | StaticValue::Flag) => String::new(),
+				None => break,
+			},
+		};
+		count += 1;
+		if value.is_empty() {
+			continue;
+		}
+		if !css.is_empty() {
+			css.push(';');
+		}
+		write_property_name(&mut css, name);
+		_ = write!(css, ":{value}");
+	}
+	obj.props.drain(..count);
+	css
+}
+
+/// Converts a camelCased property to its CSS name, e.g. `marginTop` to `margin-top` and
+/// `msTransform` to `-ms-transform`. Custom properties are left untouched.
+fn write_property_name(css: &mut String, name: &str) {
+	if name.starts_with("--") {
+		css.push_str(name);
+		return;
+	}
+	if name.starts_with("ms") && name[2..].starts_with(|c: char| c.is_ascii_uppercase()) {
+		css.push('-');
+	}
+	for c in name.chars() {
+		if c.is_ascii_uppercase() {
+			css.push('-');
+			css.push(c.to_ascii_lowercase());
+		} else {
+			css.push(c);
+		}
+	}
+}
+
+fn is_unitless(name: &str) -> bool {
+	if UNITLESS_PROPERTIES.contains(name) {
+		return true;
+	}
+	let Some(rest) = ["Webkit", "Moz", "ms", "O"]
+		.iter()
+		.find_map(|prefix| name.strip_prefix(prefix))
+		.filter(|rest| rest.starts_with(|c: char| c.is_ascii_uppercase()))
+	else {
+		return false;
+	};
+	let mut unprefixed = rest[..1].to_ascii_lowercase();
+	unprefixed.push_str(&rest[1..]);
+	UNITLESS_PROPERTIES.contains(unprefixed.as_str())
+}
@@ -2,20 +2,27 @@ use core::mem;
 use serde::Deserialize;
 use std::borrow::Cow;
 use std::fmt::Write;
+use swc_core::common::comments::Comments;
 use swc_core::common::util::take::Take;
-use swc_core::common::{Mark, Spanned, DUMMY_SP};
+use swc_core::common::{EqIgnoreSpan, Mark, Span, Spanned, DUMMY_SP};
 use swc_core::ecma::ast::*;
 use swc_core::ecma::atoms::js_word;
 use swc_core::ecma::atoms::{Atom, JsWord};
 use swc_core::ecma::utils::IdentExt;
-use swc_core::ecma::visit::{noop_visit_mut_type, VisitMut, VisitMutWith};
-use swc_core::plugin::errors::HANDLER;
+use swc_core::ecma::visit::{noop_visit_mut_type, noop_visit_type, Visit, VisitMut, VisitMutWith, VisitWith};
+
+use crate::diagnostics::Error;
+use crate::eval::Evaluator;
+use crate::pragma::Pragmas;
+use crate::style::extract_static_style;
 
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct TransformVisitor {
 	#[serde(deserialize_with = "de::expr", default = "default_template_fn")]
 	template: Option<Box<Expr>>,
+	#[serde(deserialize_with = "de::templates", default)]
+	templates: Vec<(String, Box<Expr>)>,
 	#[serde(deserialize_with = "de::str", default)]
 	import_source: Option<Str>,
 	#[serde(deserialize_with = "de::ident", default = "default_spread")]
@@ -24,13 +31,161 @@ pub struct TransformVisitor {
 	child: Ident,
 	#[serde(deserialize_with = "de::ident", default = "default_children")]
 	children: Ident,
+	#[serde(deserialize_with = "de::ident", default = "default_component")]
+	component: Ident,
+	#[serde(deserialize_with = "de::ident", default = "default_attrs")]
+	attrs: Ident,
+	#[serde(deserialize_with = "de::ident", default = "default_key")]
+	key: Ident,
+	#[serde(deserialize_with = "de::ident", default = "default_ref")]
+	r#ref: Ident,
+	#[serde(deserialize_with = "de::ident", default = "default_html")]
+	html: Ident,
+	#[serde(deserialize_with = "de::ident", default = "default_event")]
+	event: Ident,
+	#[serde(default = "default_event_prefix")]
+	event_prefix: String,
+	#[serde(default)]
+	lowercase_events: bool,
+	#[serde(default)]
+	raw_string_children: bool,
+	#[serde(default)]
+	attribute_holes: AttributeHoles,
+	#[serde(default)]
+	react_attribute_names: bool,
+	#[serde(default)]
+	serialization: Serialization,
+	#[serde(default)]
+	void_elements: Option<Vec<String>>,
+	#[serde(default)]
+	extra_void_elements: Vec<String>,
+	#[serde(deserialize_with = "de::fold_static", default)]
+	fold_static: FoldStatic,
+	#[serde(default)]
+	hoist: bool,
+	#[serde(default)]
+	xmlns: bool,
+	#[serde(default)]
+	hole_metadata: bool,
+	#[serde(skip)]
+	evaluator: Evaluator,
+	#[serde(skip)]
+	comments: Option<Box<dyn Comments>>,
+	#[serde(skip)]
+	namespace: Namespace,
+	/// Template functions that may be imported from `import_source`.
+	#[serde(skip)]
+	imports: Vec<TemplateImport>,
+	/// Local names bound by the module's import declarations.
+	#[serde(skip)]
+	imported: Vec<JsWord>,
+	/// Module-level template declarations, in order of appearance.
+	#[serde(skip)]
+	hoisted: Vec<(Ident, Box<Expr>)>,
 	#[serde(skip)]
 	quasis: Vec<String>,
+	/// The span of the node each quasi originates from.
+	#[serde(skip)]
+	spans: Vec<Span>,
 	#[serde(skip)]
 	#[allow(clippy::vec_box)]
 	exprs: Vec<Box<Expr>>,
+	/// The kind of each hole in `exprs`.
+	#[serde(skip)]
+	kinds: Vec<HoleKind>,
+}
+
+/// Where dynamic attributes are placed in the template.
+#[derive(Deserialize, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+enum AttributeHoles {
+	/// Collected into a single `{ "name": value }` hole after the static attributes.
+	#[default]
+	Object,
+	/// Emitted in place as `name="${value}"`.
+	Inline,
+}
+
+/// How elements without children are closed.
+#[derive(Deserialize, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+enum Serialization {
+	/// `<br>` and `<div></div>`.
+	#[default]
+	Html,
+	/// `<br />` and `<div></div>`.
+	Xhtml,
+	/// `<br/>` and `<div/>`.
+	Xml,
+}
+
+/// What to emit for templates without any holes.
+#[derive(Default)]
+enum FoldStatic {
+	/// A template like any other.
+	#[default]
+	Off,
+	/// A string literal, if the template function is `String.raw` or there is none.
+	Literal,
+	/// A call to the wrapper with a string literal.
+	Wrapper(Box<Expr>),
+}
+
+/// The namespace of the element being transformed.
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+enum Namespace {
+	#[default]
+	Html,
+	Svg,
+	MathMl,
+}
+
+impl Namespace {
+	/// The namespace of an element with the given name.
+	fn of(self, name: &str) -> Self {
+		match name {
+			"svg" => Namespace::Svg,
+			"math" => Namespace::MathMl,
+			_ => self,
+		}
+	}
+
+	/// The namespace of the children of an element with the given name.
+	fn children_of(self, name: &str) -> Self {
+		match (self, name) {
+			(Namespace::Svg, "foreignObject") => Namespace::Html,
+			_ => self.of(name),
+		}
+	}
+
+	fn uri(self) -> &'static str {
+		match self {
+			Namespace::Html => "http://www.w3.org/1999/xhtml",
+			Namespace::Svg => "http://www.w3.org/2000/svg",
+			Namespace::MathMl => "http://www.w3.org/1998/Math/MathML",
+		}
+	}
+
+	/// The key of the namespace in the `templates` config.
+	fn key(self) -> Option<&'static str> {
+		match self {
+			Namespace::Html => None,
+			Namespace::Svg => Some("svg"),
+			Namespace::MathMl => Some("math"),
+		}
+	}
+}
+
+struct TemplateImport {
+	local: Ident,
+	imported: Ident,
+	used: bool,
 }
 
+static VOID_ELEMENTS: phf::Set<&str> = phf::phf_set!(
+	"area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source", "track", "wbr"
+);
+
 #[inline]
 fn default_spread() -> Ident {
 	Ident::new("$$spread".into(), DUMMY_SP)
@@ -44,6 +199,34 @@ fn default_children() -> Ident {
 	Ident::new("$$children".into(), DUMMY_SP)
 }
 #[inline]
+fn default_component() -> Ident {
+	Ident::new("$$component".into(), DUMMY_SP)
+}
+#[inline]
+fn default_attrs() -> Ident {
+	Ident::new("$$attrs".into(), DUMMY_SP)
+}
+#[inline]
+fn default_key() -> Ident {
+	Ident::new("$$key".into(), DUMMY_SP)
+}
+#[inline]
+fn default_ref() -> Ident {
+	Ident::new("$$ref".into(), DUMMY_SP)
+}
+#[inline]
+fn default_html() -> Ident {
+	Ident::new("$$html".into(), DUMMY_SP)
+}
+#[inline]
+fn default_event() -> Ident {
+	Ident::new("$$event".into(), DUMMY_SP)
+}
+#[inline]
+fn default_event_prefix() -> String {
+	"on".into()
+}
+#[inline]
 fn default_template_fn() -> Option<Box<Expr>> {
 	Some(Box::new(Expr::Member(MemberExpr {
 		span: DUMMY_SP,
@@ -58,142 +241,401 @@ impl Default for TransformVisitor {
 			template: default_template_fn(),
 			child: default_child(),
 			children: default_children(),
+			component: default_component(),
+			attrs: default_attrs(),
+			key: default_key(),
+			r#ref: default_ref(),
+			html: default_html(),
+			event: default_event(),
+			event_prefix: default_event_prefix(),
+			lowercase_events: false,
 			spread: default_spread(),
+			templates: vec![],
 			import_source: None,
+			raw_string_children: false,
+			attribute_holes: AttributeHoles::Object,
+			react_attribute_names: false,
+			serialization: Serialization::Html,
+			void_elements: None,
+			extra_void_elements: vec![],
+			fold_static: FoldStatic::Off,
+			hoist: false,
+			xmlns: false,
+			hole_metadata: false,
+			hoisted: vec![],
+			evaluator: Default::default(),
+			comments: None,
+			namespace: Namespace::Html,
+			imports: vec![],
+			imported: vec![],
 			quasis: vec![],
+			spans: vec![],
+			kinds: vec![],
 			exprs: vec![],
 		}
 	}
 }
 
 impl TransformVisitor {
+	/// Allows `undefined` to be evaluated at compile time, unless it is shadowed by a local binding.
+	pub fn with_unresolved_mark(mut self, unresolved_mark: Mark) -> Self {
+		self.evaluator = Evaluator::with_unresolved_mark(unresolved_mark);
+		self
+	}
+
+	/// Allows pragma comments such as `/** @jsxTemplate html */` to override the config for a file.
+	pub fn with_comments(mut self, comments: impl Comments + 'static) -> Self {
+		self.comments = Some(Box::new(comments));
+		self
+	}
+
+	/// Applies the pragmas in the leading comments of a file, and returns whether to transform it.
+	fn apply_pragmas(&mut self, span: Span) -> bool {
+		let Some(leading) = self
+			.comments
+			.as_ref()
+			.and_then(|comments| comments.get_leading(span.lo))
+		else {
+			return true;
+		};
+		let pragmas = Pragmas::parse(&leading);
+		if let Some(template) = pragmas.template {
+			self.template = Some(template);
+			self.templates.retain(|(key, _)| key != "default");
+		}
+		if let Some(import_source) = pragmas.import_source {
+			self.import_source = Some(import_source);
+		}
+		!pragmas.disabled
+	}
+
 	#[inline]
 	fn quasi_last_mut(&mut self) -> &mut String {
 		self.quasis.last_mut().unwrap()
 	}
 
 	#[inline]
-	fn push(&mut self, expr: Box<Expr>) {
-		self.quasis.push(" ".to_string());
+	fn push_quasi(&mut self, quasi: String, span: Span) {
+		self.quasis.push(quasi);
+		self.spans.push(span);
+	}
+
+	#[inline]
+	fn push_hole(&mut self, kind: HoleKind, expr: Box<Expr>) {
 		self.exprs.push(expr);
+		self.kinds.push(kind);
+	}
+
+	/// Pushes a hole in attribute position.
+	#[inline]
+	fn push(&mut self, kind: HoleKind, expr: Box<Expr>) {
+		self.push_quasi(" ".to_string(), expr.span());
+		self.push_hole(kind, expr);
+	}
+
+	/// Pushes a hole in child position.
+	#[inline]
+	fn push_child(&mut self, kind: HoleKind, expr: Box<Expr>) {
+		self.push_quasi(String::new(), expr.span());
+		self.push_hole(kind, expr);
+	}
+
+	fn is_void_element(&self, name: &str) -> bool {
+		let is_void = match &self.void_elements {
+			Some(void_elements) => void_elements.iter().any(|elt| elt == name),
+			None => VOID_ELEMENTS.contains(name),
+		};
+		is_void || self.extra_void_elements.iter().any(|elt| elt == name)
+	}
+
+	fn attr_name<'a>(&self, name: &'a JSXAttrName) -> Cow<'a, str> {
+		let name = jsx_attr_name_as_str(name);
+		match self
+			.react_attribute_names
+			.then(|| react_attr_name_as_html(&name))
+			.flatten()
+		{
+			Some(html_name) => Cow::Borrowed(html_name),
+			None => name,
+		}
+	}
+
+	/// The hole key for attributes that are never rendered, like `key` and `ref`.
+	fn reserved_key(&self, name: &str) -> Option<(&Ident, HoleKind)> {
+		match name {
+			"key" => Some((&self.key, HoleKind::Key)),
+			"ref" => Some((&self.r#ref, HoleKind::Ref)),
+			_ => None,
+		}
+	}
+
+	/// Reports attribute names that would be mistaken for a hole, such as `$$child`.
+	fn check_attr_name(&self, name: &str, span: Span) {
+		let reserved = [
+			&self.spread,
+			&self.child,
+			&self.children,
+			&self.component,
+			&self.attrs,
+			&self.key,
+			&self.r#ref,
+			&self.html,
+			&self.event,
+		];
+		if reserved.iter().any(|key| &*key.sym == name) {
+			Error::ReservedAttributeName {
+				span,
+				name: name.into(),
+			}
+			.emit();
+		}
+	}
+
+	/// The event name of an event handler attribute such as `onClick`.
+	///
+	/// If the prefix ends with a letter or digit, the event name must start with an uppercase letter,
+	/// so that attributes like `one` are left alone.
+	fn event_name<'a>(&self, name: &'a str) -> Option<Cow<'a, str>> {
+		let event = name.strip_prefix(self.event_prefix.as_str())?;
+		let first = event.chars().next()?;
+		let needs_uppercase = self.event_prefix.ends_with(|c: char| c.is_alphanumeric());
+		if needs_uppercase && !first.is_uppercase() {
+			return None;
+		}
+		Some(match self.lowercase_events {
+			true => event.to_lowercase().into(),
+			false => event.into(),
+		})
+	}
+
+	/// Transforms any JSX nested inside an expression that is about to become a hole.
+	#[inline]
+	fn visit_hole(&mut self, mut expr: Box<Expr>) -> Box<Expr> {
+		expr.visit_mut_with(self);
+		expr
 	}
 
 	fn fold_jsx_element(&mut self, elt: &mut JSXElement) {
-		let JSXOpeningElement { span, name, attrs, .. } = &mut elt.opening;
-		let name = match name {
-			JSXElementName::JSXNamespacedName(name) => format!("{}:{}", name.ns.sym, name.name.sym),
-			JSXElementName::JSXMemberExpr(..) => unreachable(span.take()),
-			JSXElementName::Ident(ident) => ident.sym.to_string(),
+		let Some(name) = host_element_name(&elt.opening.name) else {
+			self.fold_jsx_component(elt);
+			return;
 		};
+		let outer = self.namespace;
+		let namespace = outer.of(&name);
+		// Standalone SVG and MathML markup needs namespace declarations on its outermost element.
+		let declare_xmlns =
+			self.xmlns && namespace != Namespace::Html && (namespace != outer || self.quasis.is_empty());
+		self.namespace = outer.children_of(&name);
+		self.fold_host_element(elt, name, namespace, declare_xmlns);
+		self.namespace = outer;
+	}
 
-		fn extract_static_attr_pair(attr: &JSXAttrOrSpread) -> Option<(&JSXAttrName, Option<Cow<str>>)> {
+	fn fold_host_element(&mut self, elt: &mut JSXElement, name: String, namespace: Namespace, declare_xmlns: bool) {
+		fn extract_static_attr_pair(attr: &JSXAttrOrSpread) -> Option<(&JSXAttrName, StaticValue<'_>)> {
 			match attr {
 				JSXAttrOrSpread::JSXAttr(JSXAttr { name, value, .. }) => match value {
-					Some(JSXAttrValue::Lit(Lit::Str(Str { value, .. }))) => Some((name, Some(value.as_ref().into()))),
-					Some(JSXAttrValue::Lit(other_lit)) => {
-						HANDLER.with(|handler| handler.span_bug(other_lit.span(), "Impossible JSX attribute value"))
+					Some(JSXAttrValue::Lit(Lit::Str(Str { value, .. }))) => {
+						Some((name, StaticValue::Str(value.as_ref().into())))
 					}
 					Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
 						expr: JSXExpr::Expr(expr),
 						..
-					})) => match expr.as_ref() {
-						Expr::Tpl(tpl) if tpl.exprs.is_empty() => {
-							let [TplElement { cooked, raw, .. }] = &tpl.quasis[..] else {
-								unreachable(tpl.span)
-							};
-							Some((name, Some(cooked.as_deref().unwrap_or(raw.trim()).into())))
-						}
-						Expr::Lit(Lit::Bool(value)) if value.value => Some((name, None)),
-						Expr::Lit(Lit::Str(Str { value, .. })) => Some((name, Some(value.as_ref().into()))),
-						Expr::Lit(Lit::Num(Number { value, .. })) => Some((name, Some(value.to_string().into()))),
-						_ => None,
-					},
+					})) => static_value(expr).map(|value| (name, value)),
 					Some(_) => None,
-					None => Some((name, None)),
+					None => Some((name, StaticValue::Flag)),
 				},
 				_ => None,
 			}
 		}
 
-		let leading_static_attrs = attrs
-			.iter()
-			.map_while(extract_static_attr_pair)
-			.map(|(name, value)| {
-				let name = jsx_attr_name_as_str(name);
-				match value {
-					Some(value) => format!("{name}=\"{value}\""),
-					None => name.to_string(),
-				}
-			})
-			.collect::<Vec<_>>();
-		let leading_static_attrs_count = leading_static_attrs.len();
-		let leading_static_attrs = leading_static_attrs.join(" ");
-		let first = if leading_static_attrs.is_empty() {
-			format!("<{name} ")
-		} else {
-			format!("<{name} {} ", leading_static_attrs)
-		};
-
+		let first = format!("<{name} ");
 		if self.quasis.is_empty() {
-			self.quasis.push(first);
+			self.push_quasi(first, elt.span);
 		} else {
 			_ = self.quasi_last_mut().write_str(&first);
 		}
+		if declare_xmlns {
+			let declared = |xmlns: &str| {
+				elt.opening.attrs.iter().any(|attr| match attr {
+					JSXAttrOrSpread::JSXAttr(JSXAttr { name, .. }) => jsx_attr_name_as_str(name) == xmlns,
+					JSXAttrOrSpread::SpreadElement(..) => false,
+				})
+			};
+			let uses_xlink = namespace == Namespace::Svg && !declared("xmlns:xlink") && {
+				let mut finder = FindXlink {
+					react_attribute_names: self.react_attribute_names,
+					found: false,
+				};
+				elt.visit_with(&mut finder);
+				finder.found
+			};
+			let declare_default = !declared("xmlns");
+			let last = self.quasi_last_mut();
+			if declare_default {
+				write_static_attr(last, "xmlns", &StaticValue::Str(namespace.uri().into()));
+			}
+			if uses_xlink {
+				write_static_attr(last, "xmlns:xlink", &StaticValue::Str(XLINK_NAMESPACE.into()));
+			}
+		}
 
 		let mut props = vec![];
-		for attr in attrs.iter_mut().skip(leading_static_attrs_count) {
-			if let Some((name, value)) = extract_static_attr_pair(attr) {
-				let name = jsx_attr_name_as_str(name);
-				let last = self.quasi_last_mut();
-				if let Some(value) = value {
-					_ = write!(last, "{name}=\"{value}\" ");
-				} else {
-					_ = write!(last, "{name} ");
+		let mut inner_html = None;
+		for attr in elt.opening.attrs.iter_mut() {
+			if let JSXAttrOrSpread::JSXAttr(JSXAttr { name, .. }) = attr {
+				let attr_name = self.attr_name(name);
+				self.check_attr_name(&attr_name, name.span());
+				if self.xmlns
+					&& namespace != Namespace::Html
+					&& attr_name.contains(':')
+					&& !NAMESPACED_ATTRIBUTES.contains(&attr_name)
+				{
+					Error::UnknownNamespacedAttribute {
+						span: name.span(),
+						name: attr_name.into_owned(),
+					}
+					.emit();
+				}
+			}
+			if let JSXAttrOrSpread::JSXAttr(JSXAttr {
+				name: JSXAttrName::Ident(ident),
+				value,
+				span,
+			}) = attr
+			{
+				if let Some((key, kind)) = self.reserved_key(&ident.sym) {
+					let key = key.clone();
+					let value = self.visit_hole(take_jsx_attr(value));
+					self.push(kind, hole(&key, value));
+					continue;
 				}
+				if let Some(html) = take_inner_html(&ident.sym, value) {
+					inner_html = Some((*span, html));
+					continue;
+				}
+			}
+			if let JSXAttrOrSpread::JSXAttr(JSXAttr {
+				value:
+					Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+						expr: JSXExpr::Expr(expr),
+						..
+					})),
+				..
+			}) = attr
+			{
+				self.evaluator.fold(expr);
+			}
+			if let Some((name, value)) = extract_static_attr_pair(attr) {
+				let name = self.attr_name(name);
+				write_static_attr(self.quasi_last_mut(), &name, &value);
 				continue;
 			}
 			match attr {
-				JSXAttrOrSpread::JSXAttr(JSXAttr { name, value, .. }) => {
-					let name = jsx_attr_name_as_str(name);
-					if let Some(value) = value {
-						let value = match value {
-							JSXAttrValue::Lit(lit) => Box::new(Expr::Lit(take_lit(lit))),
-							JSXAttrValue::JSXExprContainer(JSXExprContainer { expr, .. }) => match expr {
-								JSXExpr::JSXEmptyExpr(empty) => Box::new(Expr::JSXEmpty(*empty)),
-								JSXExpr::Expr(expr) => expr.take(),
-							},
-							JSXAttrValue::JSXElement(elt) => Box::new(Expr::JSXElement(elt.take())),
-							JSXAttrValue::JSXFragment(frag) => Box::new(Expr::JSXFragment(frag.take())),
+				JSXAttrOrSpread::JSXAttr(JSXAttr { name, value, span }) => {
+					if let Some(event) = self.event_name(&jsx_attr_name_as_str(name)) {
+						let handler = self.visit_hole(take_jsx_attr(value));
+						let event = Str {
+							span: name.span(),
+							value: event.into(),
+							raw: None,
 						};
-						props.push((JsWord::from(name), value));
+						self.push(
+							HoleKind::Event(event.value.clone()),
+							hole_with(
+								*span,
+								[
+									(self.event.clone(), handler),
+									(
+										Ident::new("name".into(), DUMMY_SP),
+										Box::new(Expr::Lit(Lit::Str(event))),
+									),
+								],
+							),
+						);
+						continue;
+					}
+					let name = self.attr_name(name);
+					if let Some(value) = value {
+						let mut value = take_jsx_attr_value(value);
+						let mut css = String::new();
+						let mut inline = self.attribute_holes == AttributeHoles::Inline;
+						if let ("style", Expr::Object(obj)) = (name.as_ref(), unwrap_expr_mut(&mut value)) {
+							let mut rest = obj.clone();
+							let static_css = extract_static_style(&mut rest, &self.evaluator);
+							if rest.props.is_empty() {
+								let css = if static_css.is_empty() {
+									StaticValue::Omit
+								} else {
+									StaticValue::Str(static_css.into())
+								};
+								write_static_attr(self.quasi_last_mut(), &name, &css);
+								continue;
+							}
+							// An object cannot be interpolated into attribute text, so a partly dynamic style
+							// object is kept whole in the attributes object instead.
+							if inline {
+								inline = false;
+							} else {
+								*obj = rest;
+								css = static_css;
+							}
+						}
+						let value = self.visit_hole(value);
+						if inline {
+							_ = write!(self.quasi_last_mut(), "{name}=\"");
+							self.push_quasi("\" ".to_string(), value.span());
+							self.push_hole(HoleKind::Attr(Some(name.into())), value);
+						} else {
+							if !css.is_empty() {
+								write_static_attr(self.quasi_last_mut(), &name, &StaticValue::Str(css.into()));
+							}
+							props.push((
+								Str {
+									span: *span,
+									value: name.into(),
+									raw: None,
+								},
+								value,
+							));
+						}
 					} else {
 						_ = write!(self.quasi_last_mut(), "{name} ");
 					}
 				}
 				JSXAttrOrSpread::SpreadElement(SpreadElement { expr, .. }) => {
-					if let Expr::Object(ObjectLit { props: obj_props, .. }) = expr.as_mut() {
+					if let Expr::Object(ObjectLit { props: obj_props, .. }) = unwrap_expr_mut(expr) {
+						for prop in obj_props.iter() {
+							if let PropOrSpread::Prop(prop) = prop {
+								if let Prop::KeyValue(KeyValueProp { key, .. }) = prop.as_ref() {
+									match key {
+										PropName::Ident(ident) => self.check_attr_name(&ident.sym, ident.span),
+										PropName::Str(str) => self.check_attr_name(&str.value, str.span),
+										_ => {}
+									}
+								}
+							}
+						}
 						let mut extractor = ExtractStaticProps {
-							buffer: self.quasi_last_mut(),
+							buffer: self.quasis.last_mut().unwrap(),
+							react_attribute_names: self.react_attribute_names,
+							evaluator: &self.evaluator,
+							key: &self.key,
+							r#ref: &self.r#ref,
+							holes: vec![],
 						};
 						obj_props.visit_mut_with(&mut extractor);
+						for (kind, value) in extractor.holes {
+							let value = self.visit_hole(value);
+							self.push(kind, value);
+						}
 						if !obj_props.is_empty() {
-							self.push(Box::new(Expr::Object(ObjectLit {
-								span: DUMMY_SP,
-								props: vec![PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
-									key: PropName::Ident(self.spread.clone()),
-									value: expr.take(),
-								})))],
-							})));
+							let value = self.visit_hole(expr.take());
+							self.push(HoleKind::Spread, hole(&self.spread, value));
 						}
 						continue;
 					}
-					self.push(Box::new(Expr::Object(ObjectLit {
-						span: DUMMY_SP,
-						props: vec![PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
-							key: PropName::Ident(self.spread.clone()),
-							value: expr.take(),
-						})))],
-					})));
+					let value = self.visit_hole(expr.take());
+					self.push(HoleKind::Spread, hole(&self.spread, value));
 				}
 			}
 		}
@@ -202,153 +644,429 @@ impl TransformVisitor {
 				.into_iter()
 				.map(|(key, value)| {
 					PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
-						key: PropName::Str(Str {
-							span: DUMMY_SP,
-							value: key,
-							raw: None,
-						}),
+						key: PropName::Str(key),
 						value,
 					})))
 				})
 				.collect();
-			self.exprs
-				.push(Box::new(Expr::Object(ObjectLit { span: DUMMY_SP, props })));
-			self.quasis.push(String::new());
+			let attrs = Box::new(Expr::Object(ObjectLit {
+				span: elt.opening.span,
+				props,
+			}));
+			self.push_child(HoleKind::Attr(None), hole(&self.attrs, attrs));
 		}
 
-		static VOID_ELEMENTS: phf::Set<&str> = phf::phf_set!(
-			"area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source", "track",
-			"wbr"
-		);
+		enum InnerHtml {
+			Markup(String),
+			Hole(Box<Expr>),
+		}
+		let is_void = self.is_void_element(&name);
+		let inner_html = inner_html.and_then(|(span, mut html)| {
+			if is_void {
+				Error::InnerHtmlOnVoidElement {
+					attr: span,
+					name: name.clone(),
+				}
+				.emit();
+				return None;
+			}
+			if let Some(child) = elt.children.iter().find(|child| !is_blank_jsx_text(child)) {
+				Error::InnerHtmlWithChildren {
+					attr: span,
+					children: child.span(),
+				}
+				.emit();
+			}
+			self.evaluator.fold(&mut html);
+			match static_value(&html) {
+				Some(StaticValue::Str(markup)) if !markup.is_empty() => Some(InnerHtml::Markup(markup.into_owned())),
+				Some(StaticValue::Str(..) | StaticValue::Omit) => None,
+				_ => Some(InnerHtml::Hole(html)),
+			}
+		});
 
-		if elt.children.is_empty() {
-			if VOID_ELEMENTS.contains(&name) {
-				self.quasi_last_mut().push('>');
-			} else {
-				self.quasi_last_mut().push_str("/>");
+		let serialization = self.serialization;
+		let last = self.quasi_last_mut();
+		if last.ends_with(' ') {
+			last.pop();
+		}
+		if elt.children.is_empty() && inner_html.is_none() {
+			match (serialization, is_void) {
+				(Serialization::Xml, _) => last.push_str("/>"),
+				(Serialization::Xhtml, true) => last.push_str(" />"),
+				(Serialization::Html, true) => last.push('>'),
+				(_, false) => _ = write!(last, "></{name}>"),
 			}
 			return;
 		}
-		{
-			let last = self.quasi_last_mut();
-			if last.ends_with(' ') {
-				last.pop();
+		last.push('>');
+		match inner_html {
+			Some(InnerHtml::Markup(markup)) => self.quasi_last_mut().push_str(&markup),
+			Some(InnerHtml::Hole(html)) => {
+				let value = self.visit_hole(html);
+				self.push_child(HoleKind::Html, hole(&self.html, value));
+			}
+			None => {
+				for child in &mut elt.children {
+					self.fold_jsx_child(child, Some(&name));
+				}
 			}
-			last.push('>');
-		}
-		for child in &mut elt.children {
-			self.fold_jsx_child(child);
 		}
 		_ = write!(self.quasi_last_mut(), "</{name}>")
 	}
 
-	fn fold_jsx_child(&mut self, child: &mut JSXElementChild) {
+	/// Components become a single hole carrying the component, its props and its children.
+	fn fold_jsx_component(&mut self, elt: &mut JSXElement) {
+		let mut reserved = vec![];
+		let props = elt
+			.opening
+			.attrs
+			.iter_mut()
+			.filter_map(|attr| match attr {
+				JSXAttrOrSpread::JSXAttr(JSXAttr { name, value, span }) => {
+					let value = self.visit_hole(take_jsx_attr(value));
+					let name = jsx_attr_name_as_str(name);
+					if let Some((key, _)) = self.reserved_key(&name) {
+						reserved.push((key.clone(), value));
+						return None;
+					}
+					Some(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+						key: PropName::Str(Str {
+							span: *span,
+							value: name.into(),
+							raw: None,
+						}),
+						value,
+					}))))
+				}
+				JSXAttrOrSpread::SpreadElement(spread) => {
+					let mut spread = spread.take();
+					spread.expr = self.visit_hole(spread.expr);
+					Some(PropOrSpread::Spread(spread))
+				}
+			})
+			.collect();
+		let mut hole = vec![(self.component.clone(), jsx_element_name_as_expr(&elt.opening.name))];
+		hole.extend(reserved);
+		hole.push((
+			Ident::new("props".into(), DUMMY_SP),
+			Box::new(Expr::Object(ObjectLit {
+				span: elt.opening.span,
+				props,
+			})),
+		));
+		if !elt.children.is_empty() {
+			let children = self.swap_state(|me| {
+				me.push_quasi(String::new(), elt.span);
+				for child in &mut elt.children {
+					me.fold_jsx_child(child, None);
+				}
+				me.replace_jsx_element(None)
+			});
+			hole.push((Ident::new("children".into(), DUMMY_SP), Box::new(children)));
+		}
+		self.push_child(HoleKind::Component, hole_with(elt.span, hole));
+	}
+
+	/// Writes static text content of the `parent` element.
+	///
+	/// Text is HTML-escaped, unless `raw_string_children` is set. The content of `script` and `style` is
+	/// never decoded by browsers, so only closing tags that would end the element early are escaped.
+	fn write_text(&mut self, text: &str, parent: Option<&str>) {
+		let raw_string_children = self.raw_string_children;
+		let last = self.quasi_last_mut();
+		match parent.filter(|parent| is_raw_text_element(parent)) {
+			Some(parent) => _ = write!(last, "{}", EscapeRawText(text, parent)),
+			None if raw_string_children => last.push_str(text),
+			None => _ = write!(last, "{}", EscapeText(text)),
+		}
+	}
+
+	/// Folds a child of the `parent` host element, or of a fragment or component if `parent` is `None`.
+	fn fold_jsx_child(&mut self, child: &mut JSXElementChild, parent: Option<&str>) {
 		match child {
 			JSXElementChild::JSXText(JSXText { value, .. }) => {
-				_ = self.quasi_last_mut().write_str(value.trim());
+				self.write_text(&clean_jsx_text(value), parent);
 			}
 			JSXElementChild::JSXElement(elt) => {
 				self.fold_jsx_element(elt);
 			}
 			JSXElementChild::JSXFragment(JSXFragment { children, .. }) => {
 				for child in children {
-					self.fold_jsx_child(child);
+					self.fold_jsx_child(child, parent);
 				}
 			}
 			JSXElementChild::JSXExprContainer(JSXExprContainer {
 				expr: JSXExpr::Expr(expr),
 				..
-			}) => match expr.as_mut() {
-				Expr::JSXElement(elt) => self.fold_jsx_element(elt),
-				Expr::JSXFragment(frag) => {
-					for child in &mut frag.children {
-						self.fold_jsx_child(child)
+			}) => {
+				self.evaluator.fold(expr);
+				match expr.as_mut() {
+					Expr::JSXElement(elt) => self.fold_jsx_element(elt),
+					Expr::JSXFragment(frag) => {
+						for child in &mut frag.children {
+							self.fold_jsx_child(child, parent)
+						}
 					}
-				}
-				Expr::Lit(Lit::Str(str)) => {
-					_ = write!(self.quasi_last_mut(), "{} ", str.value.trim());
-				}
-				Expr::Lit(Lit::Num(value)) => {
-					_ = write!(self.quasi_last_mut(), "{} ", value.value);
-				}
-				Expr::Tpl(Tpl { exprs, quasis, span }) if exprs.is_empty() => {
-					let [TplElement { cooked, raw, .. }] = &quasis[..] else {
-						unreachable(*span)
-					};
-					let value = cooked.as_ref().unwrap_or(&raw);
-					let last = self.quasi_last_mut();
-					for line in value.lines().map(str::trim).filter(|line| !line.is_empty()) {
-						_ = writeln!(last, "{line}");
+					Expr::Lit(Lit::Str(str)) => self.write_text(&str.value, parent),
+					Expr::Lit(Lit::Num(value)) => {
+						_ = write!(self.quasi_last_mut(), "{}", JsNumber(value.value));
+					}
+					expr if matches!(static_value(expr), Some(StaticValue::Omit | StaticValue::Flag)) => {}
+					Expr::Tpl(Tpl { exprs, quasis, .. }) if exprs.is_empty() && quasis.len() == 1 => {
+						let TplElement { cooked, raw, .. } = &quasis[0];
+						let value = cooked.as_ref().unwrap_or(raw);
+						for line in value.lines().map(str::trim).filter(|line| !line.is_empty()) {
+							self.write_text(line, parent);
+							self.quasi_last_mut().push('\n');
+						}
+					}
+					_ => {
+						let value = self.visit_hole(expr.take());
+						self.push_child(HoleKind::Child, hole(&self.child, value));
 					}
 				}
-				other => {
-					eprintln!("{other:?}");
-					self.push(Box::new(Expr::Object(ObjectLit {
-						span: DUMMY_SP,
-						props: vec![PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
-							key: PropName::Ident(self.child.clone()),
-							value: expr.take(),
-						})))],
-					})));
-				}
-			},
+			}
 			JSXElementChild::JSXSpreadChild(JSXSpreadChild { expr, .. }) => {
-				self.push(Box::new(Expr::Object(ObjectLit {
-					span: DUMMY_SP,
-					props: vec![PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
-						key: PropName::Ident(self.children.clone()),
-						value: expr.take(),
-					})))],
-				})));
+				let value = self.visit_hole(expr.take());
+				self.push_child(HoleKind::Children, hole(&self.children, value));
 			}
 			_ => {}
 		}
 	}
 
 	fn replace_jsx_element(&mut self, elt: Option<&mut JSXElement>) -> Expr {
+		let mut root = None;
 		if let Some(elt) = elt {
+			root = host_element_name(&elt.opening.name);
 			self.fold_jsx_element(elt);
 		}
+		let tag = self.template_for(root.as_deref());
+		let template = self.template_expr(tag);
+		match self.hoist {
+			true => self.hoist_template(template),
+			false => template,
+		}
+	}
+
+	/// The template function for a root element, or for a fragment or component children if there is no
+	/// root element.
+	///
+	/// `templates` is looked up by the name of the root element, then by its namespace, then by `default`.
+	fn template_for(&mut self, root: Option<&str>) -> Option<Box<Expr>> {
+		let namespace = root.map_or(self.namespace, |root| self.namespace.of(root));
+		let template = [root, namespace.key(), Some("default")]
+			.into_iter()
+			.flatten()
+			.find_map(|key| self.templates.iter().find(|(name, _)| name == key))
+			.map_or_else(|| self.template.clone(), |(_, template)| Some(template.clone()));
+		if let Some(Expr::Ident(ident)) = template.as_deref() {
+			for import in &mut self.imports {
+				import.used |= import.local.to_id() == ident.to_id();
+			}
+		}
+		template
+	}
+
+	fn template_expr(&mut self, tag: Option<Box<Expr>>) -> Expr {
+		let span = self.spans.first().copied().unwrap_or(DUMMY_SP);
+		let spans = mem::take(&mut self.spans);
+		let kinds = mem::take(&mut self.kinds);
+		if self.exprs.is_empty() {
+			let fold_static = match &self.fold_static {
+				FoldStatic::Wrapper(wrapper) => Some(Some(wrapper)),
+				FoldStatic::Literal if tag.as_deref().is_none_or(is_string_raw) => Some(None),
+				_ => None,
+			};
+			if let Some(wrapper) = fold_static {
+				let value = Box::new(Expr::Lit(Lit::Str(Str {
+					span,
+					value: mem::take(&mut self.quasis).concat().into(),
+					raw: None,
+				})));
+				return match wrapper {
+					Some(wrapper) => Expr::Call(CallExpr {
+						span,
+						callee: Callee::Expr(wrapper.clone()),
+						args: vec![value.into()],
+						type_args: None,
+					}),
+					None => *value,
+				};
+			}
+		}
 		let mut quasis = mem::take(&mut self.quasis)
 			.into_iter()
-			.map(|raw| {
-				let raw = Atom::new(raw);
-				TplElement {
-					span: DUMMY_SP,
-					tail: false,
-					cooked: None,
-					raw,
-				}
+			.zip(spans)
+			.map(|(cooked, span)| TplElement {
+				span,
+				tail: false,
+				raw: Atom::new(EscapeTemplate(&cooked).to_string()),
+				cooked: Some(Atom::new(cooked)),
 			})
 			.collect::<Vec<_>>();
 		quasis.last_mut().unwrap().tail = true;
-		match self.template.clone() {
-			Some(tag) => Expr::TaggedTpl(TaggedTpl {
-				span: DUMMY_SP,
-				tag,
-				type_params: None,
-				tpl: Box::new(Tpl {
-					span: DUMMY_SP,
-					exprs: mem::take(&mut self.exprs),
-					quasis,
-				}),
-			}),
+		// `String.raw` would keep the escapes, while an untagged template gives the same result without them.
+		let escaped = quasis.iter().any(|quasi| Some(&quasi.raw) != quasi.cooked.as_ref());
+		let tag = tag.filter(|tag| !(escaped && is_string_raw(tag)));
+		match tag {
+			Some(tag) => {
+				let mut exprs = mem::take(&mut self.exprs);
+				if self.hole_metadata && !exprs.is_empty() {
+					let descriptor = Expr::Array(ArrayLit {
+						span: DUMMY_SP,
+						elems: kinds.iter().map(|kind| Some(kind.descriptor().into())).collect(),
+					});
+					let descriptor = self.hoist_expr("_holes$", descriptor);
+					quasis.insert(
+						0,
+						TplElement {
+							span,
+							tail: false,
+							cooked: None,
+							raw: Atom::new(""),
+						},
+					);
+					exprs.insert(0, Box::new(Expr::Ident(descriptor)));
+				}
+				Expr::TaggedTpl(TaggedTpl {
+					span,
+					tag,
+					type_params: None,
+					tpl: Box::new(Tpl { span, exprs, quasis }),
+				})
+			}
 			None => Expr::Tpl(Tpl {
-				span: DUMMY_SP,
+				span,
 				exprs: mem::take(&mut self.exprs),
 				quasis,
 			}),
 		}
 	}
 
+	/// Moves a template into a module-level declaration.
+	///
+	/// Templates without holes are hoisted whole, unless they were folded into a string literal. For tagged
+	/// templates with holes, only the strings are hoisted, using an identity tag, and the tag function is
+	/// called with them and the hole values.
+	///
+	/// A template without holes is hoisted whole only if its tag is `String.raw` or imported, since the
+	/// declaration is placed before any other binding the tag could refer to.
+	fn hoist_template(&mut self, template: Expr) -> Expr {
+		match template {
+			Expr::Tpl(Tpl { ref exprs, .. }) if exprs.is_empty() => Expr::Ident(self.hoist_expr("_tmpl$", template)),
+			Expr::TaggedTpl(TaggedTpl { ref tag, ref tpl, .. }) if tpl.exprs.is_empty() && self.is_imported(tag) => {
+				Expr::Ident(self.hoist_expr("_tmpl$", template))
+			}
+			Expr::Call(CallExpr {
+				callee: Callee::Expr(ref callee),
+				..
+			}) if self.is_imported(callee) => Expr::Ident(self.hoist_expr("_tmpl$", template)),
+			Expr::TaggedTpl(TaggedTpl { span, tag, mut tpl, .. }) => {
+				let placeholders = tpl
+					.exprs
+					.iter()
+					.map(|_| Box::new(Expr::Lit(Lit::Num(0.0.into()))))
+					.collect();
+				let exprs = mem::replace(&mut tpl.exprs, placeholders);
+				let strings = Expr::TaggedTpl(TaggedTpl {
+					span: DUMMY_SP,
+					tag: Box::new(Expr::Paren(ParenExpr {
+						span: DUMMY_SP,
+						expr: Box::new(identity_fn()),
+					})),
+					type_params: None,
+					tpl,
+				});
+				let strings = self.hoist_expr("_tmpl$", strings);
+				Expr::Call(CallExpr {
+					span,
+					callee: Callee::Expr(tag),
+					args: [Box::new(Expr::Ident(strings))]
+						.into_iter()
+						.chain(exprs)
+						.map(Into::into)
+						.collect(),
+					type_args: None,
+				})
+			}
+			_ => template,
+		}
+	}
+
+	/// Whether `expr` is `String.raw` or refers to an import, so it can be used before the module body.
+	fn is_imported(&self, expr: &Expr) -> bool {
+		if is_string_raw(expr) {
+			return true;
+		}
+		let mut root = expr.unwrap_parens();
+		while let Expr::Member(MemberExpr { obj, .. }) = root {
+			root = obj.unwrap_parens();
+		}
+		let Expr::Ident(ident) = root else {
+			return false;
+		};
+		self.imports.iter().any(|import| import.local.to_id() == ident.to_id()) || self.imported.contains(&ident.sym)
+	}
+
+	/// Declares `expr` at the module level, reusing an identical declaration if there is one.
+	fn hoist_expr(&mut self, prefix: &str, expr: Expr) -> Ident {
+		if let Some((ident, _)) = self.hoisted.iter().find(|(_, init)| (**init).eq_ignore_span(&expr)) {
+			return ident.clone();
+		}
+		let ident = match self
+			.hoisted
+			.iter()
+			.filter(|(ident, _)| ident.sym.starts_with(prefix))
+			.count()
+		{
+			0 => Ident::new(prefix.into(), DUMMY_SP),
+			n => Ident::new(format!("{prefix}{}", n + 1).into(), DUMMY_SP),
+		};
+		let ident = Ident {
+			span: ident.span.apply_mark(Mark::new()),
+			..ident
+		};
+		self.hoisted.push((ident.clone(), Box::new(expr)));
+		ident
+	}
+
+	/// The declaration of the hoisted templates and hole descriptors, if any.
+	fn take_hoisted(&mut self) -> Option<Stmt> {
+		if self.hoisted.is_empty() {
+			return None;
+		}
+		let decls = mem::take(&mut self.hoisted)
+			.into_iter()
+			.map(|(ident, init)| VarDeclarator {
+				span: DUMMY_SP,
+				name: Pat::Ident(ident.into()),
+				init: Some(init),
+				definite: false,
+			})
+			.collect();
+		Some(Stmt::Decl(Decl::Var(Box::new(VarDecl {
+			span: DUMMY_SP,
+			kind: VarDeclKind::Const,
+			declare: false,
+			decls,
+		}))))
+	}
+
 	fn swap_state<T>(&mut self, blk: impl FnOnce(&mut Self) -> T) -> T {
 		let quasis = mem::take(&mut self.quasis);
+		let spans = mem::take(&mut self.spans);
 		let exprs = mem::take(&mut self.exprs);
+		let kinds = mem::take(&mut self.kinds);
 		let ret = blk(self);
 		let leftover_quasis = mem::replace(&mut self.quasis, quasis);
 		assert_eq!(leftover_quasis.as_slice(), &[] as &[String]);
+		let leftover_spans = mem::replace(&mut self.spans, spans);
+		assert_eq!(leftover_spans.as_slice(), &[] as &[Span]);
 		let leftover_exprs = mem::replace(&mut self.exprs, exprs);
 		assert_eq!(leftover_exprs.as_slice(), &[] as &[_]);
+		let leftover_kinds = mem::replace(&mut self.kinds, kinds);
+		assert_eq!(leftover_kinds.as_slice(), &[] as &[HoleKind]);
 		ret
 	}
 }
@@ -362,9 +1080,9 @@ impl VisitMut for TransformVisitor {
 		}
 		if let Some(mut frag) = expr_as_jsx_fragment(n) {
 			self.swap_state(|me| {
-				me.quasis.push(String::new());
+				me.push_quasi(String::new(), frag.span);
 				for child in frag.children.iter_mut() {
-					me.fold_jsx_child(child);
+					me.fold_jsx_child(child, None);
 				}
 				*n = me.replace_jsx_element(None);
 			});
@@ -372,51 +1090,168 @@ impl VisitMut for TransformVisitor {
 		}
 		n.visit_mut_children_with(self)
 	}
+	fn visit_mut_script(&mut self, n: &mut Script) {
+		if !self.apply_pragmas(n.span) {
+			return;
+		}
+		self.evaluator.collect_constants(n);
+		n.visit_mut_children_with(self);
+		if let Some(hoisted) = self.take_hoisted() {
+			n.body.insert(0, hoisted);
+		}
+	}
 	fn visit_mut_module(&mut self, n: &mut Module) {
-		if let Some(src) = self.import_source.clone() {
-			let Some(Expr::Ident(ident)) = self.template.as_mut().map(|tpl| tpl.unwrap_parens_mut()) else {
-				HANDLER.with(|handler| {
-					handler
-						.struct_err("[swc-plugin-static-jsx] incompatible template function")
-						.note("expected `template` to be an identifier because `importSource` was specified")
-						.emit()
-				});
+		if !self.apply_pragmas(n.span) {
+			return;
+		}
+		self.evaluator.collect_constants(n);
+		self.imported = n
+			.body
+			.iter()
+			.filter_map(|item| match item {
+				ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => Some(&import.specifiers),
+				_ => None,
+			})
+			.flatten()
+			.map(|specifier| match specifier {
+				ImportSpecifier::Named(ImportNamedSpecifier { local, .. })
+				| ImportSpecifier::Default(ImportDefaultSpecifier { local, .. })
+				| ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. }) => local.sym.clone(),
+			})
+			.collect();
+		let import_source = self.import_source.clone();
+		if import_source.is_some() {
+			// `template` is never used when there is a default entry in `templates`.
+			let has_default = self.templates.iter().any(|(key, _)| key == "default");
+			let templates = self
+				.template
+				.iter_mut()
+				.filter(|_| !has_default)
+				.chain(self.templates.iter_mut().map(|(_, tpl)| tpl));
+			let mut imports = mem::take(&mut self.imports);
+			for template in templates {
+				let Expr::Ident(ident) = template.unwrap_parens_mut() else {
+					Error::ImportSourceWithoutIdent.emit();
+					return;
+				};
+				match imports.iter().find(|import| import.imported.sym == ident.sym) {
+					Some(import) => *ident = import.local.clone(),
+					None => {
+						let imported = ident.clone();
+						*ident = ident.prefix("_");
+						ident.span = ident.span.apply_mark(Mark::new());
+						imports.push(TemplateImport {
+							local: ident.clone(),
+							imported,
+							used: false,
+						});
+					}
+				}
+			}
+			if self.template.is_none() && !has_default {
+				Error::ImportSourceWithoutIdent.emit();
 				return;
-			};
-			let import_ident = ident.clone();
-			*ident = ident.prefix("_");
-			ident.span = ident.span.apply_mark(Mark::new());
-			let import = ImportSpecifier::Named(ImportNamedSpecifier {
-				span: DUMMY_SP,
-				local: ident.clone(),
-				imported: Some(ModuleExportName::Ident(import_ident)),
-				is_type_only: false,
-			});
-			n.body.insert(
-				0,
-				ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
-					span: DUMMY_SP,
-					specifiers: vec![import],
-					src: Box::new(src),
-					type_only: false,
-					asserts: None,
-				})),
-			);
-		} else if let Some(Expr::Ident(ident)) = self.template.as_mut().map(|tpl| tpl.unwrap_parens_mut()) {
-			ident.span = ident.span.apply_mark(Mark::from_u32(2));
+			}
+			self.imports = imports;
+		} else {
+			let templates = self
+				.template
+				.iter_mut()
+				.chain(self.templates.iter_mut().map(|(_, tpl)| tpl));
+			for template in templates {
+				if let Expr::Ident(ident) = template.unwrap_parens_mut() {
+					ident.span = ident.span.apply_mark(Mark::from_u32(2));
+				}
+			}
 		}
 		n.visit_mut_children_with(self);
+		if let Some(src) = import_source {
+			let specifiers = mem::take(&mut self.imports)
+				.into_iter()
+				.filter(|import| import.used)
+				.map(|import| {
+					ImportSpecifier::Named(ImportNamedSpecifier {
+						span: DUMMY_SP,
+						local: import.local,
+						imported: Some(ModuleExportName::Ident(import.imported)),
+						is_type_only: false,
+					})
+				})
+				.collect::<Vec<_>>();
+			if !specifiers.is_empty() {
+				n.body.insert(
+					0,
+					ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+						span: DUMMY_SP,
+						specifiers,
+						src: Box::new(src),
+						type_only: false,
+						asserts: None,
+					})),
+				);
+			}
+		}
+		if let Some(hoisted) = self.take_hoisted() {
+			let idx = n
+				.body
+				.iter()
+				.position(|item| !matches!(item, ModuleItem::ModuleDecl(ModuleDecl::Import(..))))
+				.unwrap_or(n.body.len());
+			n.body.insert(idx, ModuleItem::Stmt(hoisted));
+		}
+	}
+}
+
+/// Finds `xlink:` attributes, which need the `xlink` prefix to be declared.
+pub struct FindXlink {
+	pub react_attribute_names: bool,
+	pub found: bool,
+}
+
+impl Visit for FindXlink {
+	noop_visit_type!();
+	fn visit_jsx_attr_name(&mut self, n: &JSXAttrName) {
+		let name = jsx_attr_name_as_str(n);
+		let name = match self
+			.react_attribute_names
+			.then(|| react_attr_name_as_html(&name))
+			.flatten()
+		{
+			Some(html_name) => html_name,
+			None => &name,
+		};
+		self.found |= name.starts_with("xlink:");
 	}
 }
 
 pub struct ExtractStaticProps<'a> {
 	pub buffer: &'a mut String,
+	pub react_attribute_names: bool,
+	pub evaluator: &'a Evaluator,
+	pub key: &'a Ident,
+	pub r#ref: &'a Ident,
+	/// `key` and `ref` holes taken out of the object.
+	pub holes: Vec<(HoleKind, Box<Expr>)>,
 }
 
 impl VisitMut for ExtractStaticProps<'_> {
 	noop_visit_mut_type!();
 	fn visit_mut_prop_or_spreads(&mut self, n: &mut Vec<PropOrSpread>) {
-		n.visit_mut_children_with(self);
+		// Only nested `...{}` object literals are flattened, never property values.
+		for elt in n.iter_mut() {
+			match elt {
+				PropOrSpread::Spread(SpreadElement { expr, .. }) => {
+					if let Expr::Object(ObjectLit { props, .. }) = unwrap_expr_mut(expr) {
+						self.visit_mut_prop_or_spreads(props);
+					}
+				}
+				PropOrSpread::Prop(prop) => {
+					if let Prop::KeyValue(prop) = prop.as_mut() {
+						self.visit_mut_key_value_prop(prop);
+					}
+				}
+			}
+		}
 		n.retain(|elt| match elt {
 			PropOrSpread::Spread(SpreadElement { expr, .. }) => match expr.as_ref() {
 				Expr::Object(ObjectLit { props, .. }) => !props.is_empty(),
@@ -434,53 +1269,49 @@ impl VisitMut for ExtractStaticProps<'_> {
 			PropName::Str(str) => str.value.as_ref(),
 			_ => return,
 		};
-		match n.value.as_mut() {
-			lit @ Expr::Lit(Lit::Str(..) | Lit::Bool(..) | Lit::Num(..) | Lit::BigInt(..)) => match lit.take() {
-				Expr::Lit(Lit::Str(str)) => {
-					_ = write!(self.buffer, "{name}=\"{}\" ", str.value);
-				}
-				Expr::Lit(Lit::Bool(bool)) => {
-					if bool.value {
-						_ = write!(self.buffer, "{name} ");
-					}
-				}
-				Expr::Lit(Lit::Num(value)) => {
-					_ = write!(self.buffer, "{name}=\"{}\" ", value.value);
-				}
-				Expr::Lit(Lit::BigInt(value)) => {
-					_ = write!(self.buffer, "{name}=\"{}\" ", value.value.to_str_radix(10));
-				}
-				_ => unreachable(n.span()),
-			},
-			Expr::Tpl(tpl) if tpl.exprs.is_empty() => {
-				let [TplElement { cooked, raw, .. }] = &tpl.quasis[..] else {
-					unreachable(tpl.span)
-				};
-				let text = cooked.as_deref().unwrap_or(raw.trim());
-				_ = self.buffer.write_str(text);
-			}
-			_ => n.visit_mut_children_with(self),
+		let reserved = match name {
+			"key" => Some((self.key, HoleKind::Key)),
+			"ref" => Some((self.r#ref, HoleKind::Ref)),
+			_ => None,
+		};
+		if let Some((key, kind)) = reserved {
+			self.holes.push((kind, hole(key, n.value.take())));
+			return;
+		}
+		let name = match self
+			.react_attribute_names
+			.then(|| react_attr_name_as_html(name))
+			.flatten()
+		{
+			Some(html_name) => html_name,
+			None => name,
+		};
+		self.evaluator.fold(&mut n.value);
+		if let Some(value) = static_value(&n.value) {
+			write_static_attr(self.buffer, name, &value);
+			n.value.take();
 		}
 	}
 }
 
+pub(crate) use de::parse_expr;
 pub use utils::*;
 mod utils {
 	use std::borrow::Cow;
+	use std::fmt::{self, Write};
 	use swc_core::common::util::take::Take;
-	use swc_core::common::{Span, Spanned};
+	use swc_core::common::{Span, Spanned, DUMMY_SP};
 	use swc_core::ecma::ast::*;
-	use swc_core::plugin::errors::HANDLER;
-
-	#[cold]
-	#[inline(never)]
-	pub fn unreachable(span: Span) -> ! {
-		HANDLER.with(|handler| handler.span_bug(span, "Encountered unreachable code"))
-	}
+	use swc_core::ecma::atoms::JsWord;
 
+	/// Takes a literal out of a JSX attribute. Strings drop their raw JSX text, which may contain
+	/// entities or line breaks, so that they are printed from their decoded value.
 	pub fn take_lit(lit: &mut Lit) -> Lit {
 		match lit {
-			Lit::Str(str) => Lit::Str(str.take()),
+			Lit::Str(str) => Lit::Str(Str {
+				raw: None,
+				..str.take()
+			}),
 			Lit::Bool(bool) => Lit::Bool(bool.take()),
 			Lit::Num(num) => Lit::Num(Number {
 				span: num.span,
@@ -507,39 +1338,582 @@ mod utils {
 	}
 
 	pub fn expr_as_jsx_elt(n: &mut Expr) -> Option<Box<JSXElement>> {
-		let span_n = n.span();
 		match n {
-			Expr::JSXElement(elt) => match &elt.opening.name {
-				JSXElementName::Ident(ident) => ident.sym.starts_with(|p: char| p.is_ascii_lowercase()).then(|| {
-					let Expr::JSXElement(elt) = n.take() else {
-						unreachable(span_n)
-					};
-					elt
-				}),
-				JSXElementName::JSXNamespacedName(..) => {
-					let Expr::JSXElement(elt) = n.take() else {
-						unreachable(span_n)
-					};
-					Some(elt)
-				}
+			Expr::JSXElement(elt) if !is_component_name(&elt.opening.name) => match n.take() {
+				Expr::JSXElement(elt) => Some(elt),
 				_ => None,
 			},
 			_ => None,
 		}
 	}
 
+	/// Collapses whitespace in JSX text the same way React does: lines are trimmed and joined
+	/// with a single space, and lines consisting only of whitespace are dropped.
+	pub fn clean_jsx_text(value: &str) -> String {
+		let lines = value
+			.split('\n')
+			.map(|line| line.strip_suffix('\r').unwrap_or(line))
+			.collect::<Vec<_>>();
+		let last_non_empty = lines
+			.iter()
+			.rposition(|line| line.contains(|c| c != ' ' && c != '\t'))
+			.unwrap_or(0);
+		let mut text = String::new();
+		for (idx, line) in lines.iter().enumerate() {
+			let line = line.replace('\t', " ");
+			let mut line = line.as_str();
+			if idx != 0 {
+				line = line.trim_start_matches(' ');
+			}
+			if idx != lines.len() - 1 {
+				line = line.trim_end_matches(' ');
+			}
+			if !line.is_empty() {
+				text.push_str(line);
+				if idx != last_non_empty {
+					text.push(' ');
+				}
+			}
+		}
+		text
+	}
+
+	/// Host elements are lowercase or namespaced, everything else is a component.
+	pub fn is_component_name(name: &JSXElementName) -> bool {
+		match name {
+			JSXElementName::Ident(ident) => !ident.sym.starts_with(|p: char| p.is_ascii_lowercase()),
+			JSXElementName::JSXMemberExpr(..) => true,
+			JSXElementName::JSXNamespacedName(..) => false,
+		}
+	}
+
+	/// The tag name of a host element, or `None` for a component.
+	pub fn host_element_name(name: &JSXElementName) -> Option<String> {
+		match name {
+			JSXElementName::JSXNamespacedName(name) => Some(format!("{}:{}", name.ns.sym, name.name.sym)),
+			JSXElementName::Ident(ident) if !is_component_name(name) => Some(ident.sym.to_string()),
+			_ => None,
+		}
+	}
+
+	pub fn jsx_element_name_as_expr(name: &JSXElementName) -> Box<Expr> {
+		fn object_as_expr(obj: &JSXObject) -> Box<Expr> {
+			match obj {
+				JSXObject::Ident(ident) if &*ident.sym == "this" => Box::new(Expr::This(ThisExpr { span: ident.span })),
+				JSXObject::Ident(ident) => Box::new(Expr::Ident(ident.clone())),
+				JSXObject::JSXMemberExpr(member) => member_as_expr(member),
+			}
+		}
+		fn member_as_expr(member: &JSXMemberExpr) -> Box<Expr> {
+			Box::new(Expr::Member(MemberExpr {
+				span: DUMMY_SP,
+				obj: object_as_expr(&member.obj),
+				prop: MemberProp::Ident(member.prop.clone()),
+			}))
+		}
+		match name {
+			JSXElementName::Ident(ident) if &*ident.sym == "this" => {
+				Box::new(Expr::This(ThisExpr { span: ident.span }))
+			}
+			JSXElementName::Ident(ident) => Box::new(Expr::Ident(ident.clone())),
+			JSXElementName::JSXMemberExpr(member) => member_as_expr(member),
+			// Namespaced names are never components, but would be passed as tag names.
+			JSXElementName::JSXNamespacedName(name) => Box::new(Expr::Lit(Lit::Str(Str {
+				span: name.span(),
+				value: format!("{}:{}", name.ns.sym, name.name.sym).into(),
+				raw: None,
+			}))),
+		}
+	}
+
+	/// Creates a hole object of the form `{ [key]: value }`.
+	pub fn hole(key: &Ident, value: Box<Expr>) -> Box<Expr> {
+		Box::new(Expr::Object(ObjectLit {
+			span: value.span(),
+			props: vec![PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+				key: PropName::Ident(key.clone()),
+				value,
+			})))],
+		}))
+	}
+
+	/// Takes the markup out of a `dangerouslySetInnerHTML` or `innerHTML` attribute.
+	pub fn take_inner_html(name: &str, value: &mut Option<JSXAttrValue>) -> Option<Box<Expr>> {
+		match name {
+			"innerHTML" => Some(take_jsx_attr(value)),
+			"dangerouslySetInnerHTML" => {
+				let mut value = take_jsx_attr(value);
+				if let Expr::Object(ObjectLit { props, .. }) = value.as_mut() {
+					if let [PropOrSpread::Prop(prop)] = &mut props[..] {
+						if let Prop::KeyValue(KeyValueProp {
+							key: PropName::Ident(Ident { sym, .. }) | PropName::Str(Str { value: sym, .. }),
+							value,
+						}) = prop.as_mut()
+						{
+							if &**sym == "__html" {
+								return Some(value.take());
+							}
+						}
+					}
+				}
+				Some(Box::new(Expr::Member(MemberExpr {
+					span: DUMMY_SP,
+					obj: value,
+					prop: MemberProp::Ident(Ident::new("__html".into(), DUMMY_SP)),
+				})))
+			}
+			_ => None,
+		}
+	}
+
+	pub fn is_blank_jsx_text(child: &JSXElementChild) -> bool {
+		matches!(child, JSXElementChild::JSXText(JSXText { value, .. }) if clean_jsx_text(value).is_empty())
+	}
+
+	/// A hole with several keys, such as a component or an event handler.
+	pub fn hole_with(span: Span, props: impl IntoIterator<Item = (Ident, Box<Expr>)>) -> Box<Expr> {
+		Box::new(Expr::Object(ObjectLit {
+			span,
+			props: props
+				.into_iter()
+				.map(|(key, value)| {
+					PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+						key: PropName::Ident(key),
+						value,
+					})))
+				})
+				.collect(),
+		}))
+	}
+
+	/// `s => s`, which evaluates a tagged template to its strings array.
+	pub fn identity_fn() -> Expr {
+		let param = Ident::new("s".into(), DUMMY_SP);
+		Expr::Arrow(ArrowExpr {
+			span: DUMMY_SP,
+			params: vec![Pat::Ident(param.clone().into())],
+			body: Box::new(BlockStmtOrExpr::Expr(Box::new(Expr::Ident(param)))),
+			is_async: false,
+			is_generator: false,
+			type_params: None,
+			return_type: None,
+		})
+	}
+
+	pub fn take_jsx_attr_value(value: &mut JSXAttrValue) -> Box<Expr> {
+		match value {
+			JSXAttrValue::Lit(lit) => Box::new(Expr::Lit(take_lit(lit))),
+			JSXAttrValue::JSXExprContainer(JSXExprContainer { expr, .. }) => match expr {
+				JSXExpr::JSXEmptyExpr(empty) => Box::new(Expr::JSXEmpty(*empty)),
+				JSXExpr::Expr(expr) => expr.take(),
+			},
+			JSXAttrValue::JSXElement(elt) => Box::new(Expr::JSXElement(elt.take())),
+			JSXAttrValue::JSXFragment(frag) => Box::new(Expr::JSXFragment(frag.take())),
+		}
+	}
+
+	/// Like [take_jsx_attr_value], with `true` for attributes without a value.
+	pub fn take_jsx_attr(value: &mut Option<JSXAttrValue>) -> Box<Expr> {
+		match value {
+			Some(value) => take_jsx_attr_value(value),
+			None => Box::new(Expr::Lit(Lit::Bool(Bool {
+				span: DUMMY_SP,
+				value: true,
+			}))),
+		}
+	}
+
 	pub fn expr_as_jsx_fragment(n: &mut Expr) -> Option<JSXFragment> {
-		let span_n = n.span();
-		if let Expr::JSXFragment(..) = n {
-			let Expr::JSXFragment(frag) = n.take() else {
-				unreachable(span_n)
+		match n {
+			Expr::JSXFragment(..) => match n.take() {
+				Expr::JSXFragment(frag) => Some(frag),
+				_ => None,
+			},
+			_ => None,
+		}
+	}
+
+	/// What a hole holds, as described to the runtime by `holeMetadata`.
+	#[derive(Debug, PartialEq, Eq)]
+	pub enum HoleKind {
+		/// An object of dynamic attributes, or a single attribute value with `attributeHoles: "inline"`.
+		Attr(Option<JsWord>),
+		Spread,
+		Key,
+		Ref,
+		Event(JsWord),
+		Html,
+		Child,
+		Children,
+		Component,
+	}
+
+	impl HoleKind {
+		/// An array such as `["attr"]` or `["event", "click"]`.
+		pub fn descriptor(&self) -> Expr {
+			let (kind, name) = match self {
+				HoleKind::Attr(name) => ("attr", name.as_ref()),
+				HoleKind::Spread => ("spread", None),
+				HoleKind::Key => ("key", None),
+				HoleKind::Ref => ("ref", None),
+				HoleKind::Event(name) => ("event", Some(name)),
+				HoleKind::Html => ("html", None),
+				HoleKind::Child => ("child", None),
+				HoleKind::Children => ("children", None),
+				HoleKind::Component => ("component", None),
+			};
+			let str = |value: JsWord| {
+				Some(ExprOrSpread::from(Box::new(Expr::Lit(Lit::Str(Str {
+					span: DUMMY_SP,
+					value,
+					raw: None,
+				})))))
 			};
-			return Some(frag);
+			Expr::Array(ArrayLit {
+				span: DUMMY_SP,
+				elems: [Some(kind.into()), name.cloned()]
+					.into_iter()
+					.flatten()
+					.map(str)
+					.collect(),
+			})
+		}
+	}
+
+	/// A value that is known at compile time.
+	pub enum StaticValue<'a> {
+		/// `false`, `null` or `undefined`, which render nothing.
+		Omit,
+		/// `true`, which renders a bare attribute.
+		Flag,
+		Str(Cow<'a, str>),
+	}
+
+	pub fn static_value(expr: &Expr) -> Option<StaticValue<'_>> {
+		match expr {
+			Expr::Lit(Lit::Str(Str { value, .. })) => Some(StaticValue::Str(value.as_ref().into())),
+			Expr::Lit(Lit::Num(Number { value, .. })) => Some(StaticValue::Str(JsNumber(*value).to_string().into())),
+			Expr::Lit(Lit::BigInt(BigInt { value, .. })) => Some(StaticValue::Str(value.to_str_radix(10).into())),
+			Expr::Lit(Lit::Bool(Bool { value: true, .. })) => Some(StaticValue::Flag),
+			Expr::Lit(Lit::Bool(..) | Lit::Null(..)) => Some(StaticValue::Omit),
+			Expr::Unary(UnaryExpr {
+				op: UnaryOp::Void, arg, ..
+			}) if arg.is_lit() => Some(StaticValue::Omit),
+			Expr::Tpl(Tpl { exprs, quasis, .. }) if exprs.is_empty() => match &quasis[..] {
+				[TplElement { cooked, raw, .. }] => {
+					Some(StaticValue::Str(cooked.as_deref().unwrap_or(raw.trim()).into()))
+				}
+				_ => None,
+			},
+			_ => None,
+		}
+	}
+
+	/// Writes a static attribute followed by a space, or nothing if the attribute is omitted.
+	pub fn write_static_attr(buffer: &mut String, name: &str, value: &StaticValue) {
+		match value {
+			StaticValue::Omit => {}
+			StaticValue::Flag => _ = write!(buffer, "{name} "),
+			StaticValue::Str(value) => _ = write!(buffer, "{name}=\"{}\" ", EscapeAttr(value)),
 		}
-		None
 	}
 
-	pub fn jsx_attr_name_as_str(attr: &JSXAttrName) -> Cow<str> {
+	/// Formats a number like JavaScript's `Number.prototype.toString`, so `1 / 0` is `Infinity`, `-0` is
+	/// `0` and `1e-7` stays `1e-7`.
+	pub struct JsNumber(pub f64);
+
+	impl fmt::Display for JsNumber {
+		fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+			let value = self.0;
+			if value.is_nan() {
+				return f.write_str("NaN");
+			}
+			if value == 0.0 {
+				return f.write_str("0");
+			}
+			if value < 0.0 {
+				f.write_str("-")?;
+			}
+			let value = value.abs();
+			if value.is_infinite() {
+				return f.write_str("Infinity");
+			}
+			// Rust's `{:e}` yields the shortest digits that round-trip, as JavaScript does.
+			let exp = format!("{value:e}");
+			let (mantissa, exponent) = exp.split_once('e').unwrap();
+			let digits = mantissa.replace('.', "");
+			let k = digits.len() as i32;
+			let n = exponent.parse::<i32>().unwrap() + 1;
+			if k <= n && n <= 21 {
+				write!(f, "{digits}{}", "0".repeat((n - k) as usize))
+			} else if 0 < n && n <= 21 {
+				let (int, frac) = digits.split_at(n as usize);
+				write!(f, "{int}.{frac}")
+			} else if -6 < n && n <= 0 {
+				write!(f, "0.{}{digits}", "0".repeat(-n as usize))
+			} else {
+				let sign = if n > 0 { '+' } else { '-' };
+				let (first, rest) = digits.split_at(1);
+				match rest {
+					"" => write!(f, "{first}e{sign}{}", (n - 1).abs()),
+					_ => write!(f, "{first}.{rest}e{sign}{}", (n - 1).abs()),
+				}
+			}
+		}
+	}
+
+	/// Escapes a static value for use inside a double-quoted attribute.
+	pub struct EscapeAttr<'a>(pub &'a str);
+
+	/// Escapes a static value for use as text content.
+	pub struct EscapeText<'a>(pub &'a str);
+
+	impl fmt::Display for EscapeAttr<'_> {
+		fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+			write_escaped(f, self.0, &['&', '"', '<'])
+		}
+	}
+
+	impl fmt::Display for EscapeText<'_> {
+		fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+			write_escaped(f, self.0, &['&', '<', '>'])
+		}
+	}
+
+	/// Escapes closing tags such as `</script` in the content of the raw text element named by the second
+	/// field, as `<\/script`.
+	pub struct EscapeRawText<'a>(pub &'a str, pub &'a str);
+
+	impl fmt::Display for EscapeRawText<'_> {
+		fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+			let EscapeRawText(mut rest, tag) = *self;
+			while let Some(idx) = rest.find("</") {
+				let end = idx + 2 + tag.len();
+				let closes = rest
+					.get(idx + 2..end)
+					.is_some_and(|name| name.eq_ignore_ascii_case(tag));
+				f.write_str(&rest[..idx])?;
+				f.write_str(if closes { "<\\/" } else { "</" })?;
+				rest = &rest[idx + 2..];
+			}
+			f.write_str(rest)
+		}
+	}
+
+	/// Escapes static markup for use in a template literal, so that it cannot end the template or start
+	/// a substitution.
+	pub struct EscapeTemplate<'a>(pub &'a str);
+
+	impl fmt::Display for EscapeTemplate<'_> {
+		fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+			let mut rest = self.0;
+			while let Some(idx) = rest.find(['\\', '`', '$']) {
+				f.write_str(&rest[..idx])?;
+				match rest.as_bytes()[idx] {
+					b'\\' => f.write_str("\\\\")?,
+					b'`' => f.write_str("\\`")?,
+					_ if rest[idx..].starts_with("${") => f.write_str("\\$")?,
+					_ => f.write_str("$")?,
+				}
+				rest = &rest[idx + 1..];
+			}
+			f.write_str(rest)
+		}
+	}
+
+	fn write_escaped(f: &mut fmt::Formatter<'_>, mut rest: &str, special: &[char]) -> fmt::Result {
+		while let Some(idx) = rest.find(special) {
+			f.write_str(&rest[..idx])?;
+			f.write_str(match rest.as_bytes()[idx] {
+				b'&' => "&amp;",
+				b'"' => "&quot;",
+				b'<' => "&lt;",
+				_ => "&gt;",
+			})?;
+			rest = &rest[idx + 1..];
+		}
+		f.write_str(rest)
+	}
+
+	/// React prop names that differ from their HTML or SVG attribute names.
+	static REACT_ATTRIBUTES: phf::Map<&str, &str> = phf::phf_map! {
+		"acceptCharset" => "accept-charset",
+		"accessKey" => "accesskey",
+		"allowFullScreen" => "allowfullscreen",
+		"autoCapitalize" => "autocapitalize",
+		"autoComplete" => "autocomplete",
+		"autoFocus" => "autofocus",
+		"autoPlay" => "autoplay",
+		"cellPadding" => "cellpadding",
+		"cellSpacing" => "cellspacing",
+		"charSet" => "charset",
+		"classID" => "classid",
+		"className" => "class",
+		"colSpan" => "colspan",
+		"contentEditable" => "contenteditable",
+		"crossOrigin" => "crossorigin",
+		"dateTime" => "datetime",
+		"defaultChecked" => "checked",
+		"defaultValue" => "value",
+		"encType" => "enctype",
+		"enterKeyHint" => "enterkeyhint",
+		"fetchPriority" => "fetchpriority",
+		"formAction" => "formaction",
+		"formEncType" => "formenctype",
+		"formMethod" => "formmethod",
+		"formNoValidate" => "formnovalidate",
+		"formTarget" => "formtarget",
+		"frameBorder" => "frameborder",
+		"hrefLang" => "hreflang",
+		"htmlFor" => "for",
+		"httpEquiv" => "http-equiv",
+		"inputMode" => "inputmode",
+		"isMap" => "ismap",
+		"itemID" => "itemid",
+		"itemProp" => "itemprop",
+		"itemRef" => "itemref",
+		"itemScope" => "itemscope",
+		"itemType" => "itemtype",
+		"marginHeight" => "marginheight",
+		"marginWidth" => "marginwidth",
+		"maxLength" => "maxlength",
+		"mediaGroup" => "mediagroup",
+		"minLength" => "minlength",
+		"noModule" => "nomodule",
+		"noValidate" => "novalidate",
+		"playsInline" => "playsinline",
+		"readOnly" => "readonly",
+		"referrerPolicy" => "referrerpolicy",
+		"rowSpan" => "rowspan",
+		"spellCheck" => "spellcheck",
+		"srcDoc" => "srcdoc",
+		"srcLang" => "srclang",
+		"srcSet" => "srcset",
+		"tabIndex" => "tabindex",
+		"useMap" => "usemap",
+		// SVG
+		"alignmentBaseline" => "alignment-baseline",
+		"baselineShift" => "baseline-shift",
+		"clipPath" => "clip-path",
+		"clipRule" => "clip-rule",
+		"colorInterpolation" => "color-interpolation",
+		"colorInterpolationFilters" => "color-interpolation-filters",
+		"dominantBaseline" => "dominant-baseline",
+		"enableBackground" => "enable-background",
+		"fillOpacity" => "fill-opacity",
+		"fillRule" => "fill-rule",
+		"floodColor" => "flood-color",
+		"floodOpacity" => "flood-opacity",
+		"fontFamily" => "font-family",
+		"fontSize" => "font-size",
+		"fontSizeAdjust" => "font-size-adjust",
+		"fontStretch" => "font-stretch",
+		"fontStyle" => "font-style",
+		"fontVariant" => "font-variant",
+		"fontWeight" => "font-weight",
+		"imageRendering" => "image-rendering",
+		"letterSpacing" => "letter-spacing",
+		"lightingColor" => "lighting-color",
+		"markerEnd" => "marker-end",
+		"markerMid" => "marker-mid",
+		"markerStart" => "marker-start",
+		"paintOrder" => "paint-order",
+		"pointerEvents" => "pointer-events",
+		"shapeRendering" => "shape-rendering",
+		"stopColor" => "stop-color",
+		"stopOpacity" => "stop-opacity",
+		"strokeDasharray" => "stroke-dasharray",
+		"strokeDashoffset" => "stroke-dashoffset",
+		"strokeLinecap" => "stroke-linecap",
+		"strokeLinejoin" => "stroke-linejoin",
+		"strokeMiterlimit" => "stroke-miterlimit",
+		"strokeOpacity" => "stroke-opacity",
+		"strokeWidth" => "stroke-width",
+		"textAnchor" => "text-anchor",
+		"textDecoration" => "text-decoration",
+		"textRendering" => "text-rendering",
+		"unicodeBidi" => "unicode-bidi",
+		"vectorEffect" => "vector-effect",
+		"wordSpacing" => "word-spacing",
+		"writingMode" => "writing-mode",
+		"xlinkActuate" => "xlink:actuate",
+		"xlinkArcrole" => "xlink:arcrole",
+		"xlinkHref" => "xlink:href",
+		"xlinkRole" => "xlink:role",
+		"xlinkShow" => "xlink:show",
+		"xlinkTitle" => "xlink:title",
+		"xlinkType" => "xlink:type",
+		"xmlBase" => "xml:base",
+		"xmlLang" => "xml:lang",
+		"xmlSpace" => "xml:space",
+		"xmlnsXlink" => "xmlns:xlink",
+	};
+
+	pub static XLINK_NAMESPACE: &str = "http://www.w3.org/1999/xlink";
+
+	/// Namespaced attributes that are valid on SVG and MathML elements.
+	pub static NAMESPACED_ATTRIBUTES: phf::Set<&str> = phf::phf_set!(
+		"xlink:actuate",
+		"xlink:arcrole",
+		"xlink:href",
+		"xlink:role",
+		"xlink:show",
+		"xlink:title",
+		"xlink:type",
+		"xml:base",
+		"xml:lang",
+		"xml:space",
+		"xmlns:xlink",
+	);
+
+	pub fn react_attr_name_as_html(name: &str) -> Option<&'static str> {
+		REACT_ATTRIBUTES.get(name).copied()
+	}
+
+	pub fn is_string_raw(expr: &Expr) -> bool {
+		match expr {
+			Expr::Member(MemberExpr {
+				obj,
+				prop: MemberProp::Ident(prop),
+				..
+			}) => matches!(obj.as_ref(), Expr::Ident(obj) if &*obj.sym == "String") && &*prop.sym == "raw",
+			Expr::Paren(ParenExpr { expr, .. }) => is_string_raw(expr),
+			_ => false,
+		}
+	}
+
+	/// Elements whose text content is not decoded by browsers, so it must not be escaped.
+	pub fn is_raw_text_element(name: &str) -> bool {
+		matches!(name, "script" | "style")
+	}
+
+	/// Looks through parentheses and TypeScript wrappers that do not change the value, such as
+	/// `as const`, `satisfies T` and `!`.
+	pub fn unwrap_expr(expr: &Expr) -> &Expr {
+		match expr {
+			Expr::Paren(ParenExpr { expr, .. })
+			| Expr::TsAs(TsAsExpr { expr, .. })
+			| Expr::TsSatisfies(TsSatisfiesExpr { expr, .. })
+			| Expr::TsNonNull(TsNonNullExpr { expr, .. })
+			| Expr::TsConstAssertion(TsConstAssertion { expr, .. })
+			| Expr::TsTypeAssertion(TsTypeAssertion { expr, .. }) => unwrap_expr(expr),
+			_ => expr,
+		}
+	}
+
+	pub fn unwrap_expr_mut(expr: &mut Expr) -> &mut Expr {
+		match expr {
+			Expr::Paren(ParenExpr { expr, .. })
+			| Expr::TsAs(TsAsExpr { expr, .. })
+			| Expr::TsSatisfies(TsSatisfiesExpr { expr, .. })
+			| Expr::TsNonNull(TsNonNullExpr { expr, .. })
+			| Expr::TsConstAssertion(TsConstAssertion { expr, .. })
+			| Expr::TsTypeAssertion(TsTypeAssertion { expr, .. }) => unwrap_expr_mut(expr),
+			_ => expr,
+		}
+	}
+
+	pub fn jsx_attr_name_as_str(attr: &JSXAttrName) -> Cow<'_, str> {
 		match attr {
 			JSXAttrName::Ident(ident) => Cow::Borrowed(&ident.sym),
 			JSXAttrName::JSXNamespacedName(name) => Cow::Owned(format!("{}:{}", name.ns.sym, name.name.sym)),
@@ -548,11 +1922,14 @@ mod utils {
 }
 
 mod de {
-	use serde::{de::Visitor, Deserializer};
+	use super::FoldStatic;
+	use serde::de::{MapAccess, Visitor};
+	use serde::Deserializer;
 	use swc_core::common::{BytePos, DUMMY_SP};
 	use swc_core::ecma::ast::{Expr, Ident, Str};
 	use swc_core::ecma::parser::{Parser, StringInput, Syntax};
-	use swc_core::plugin::errors::HANDLER;
+
+	use crate::diagnostics::with_handler;
 
 	pub fn expr<'de, D>(de: D) -> Result<Option<Box<Expr>>, D::Error>
 	where
@@ -568,18 +1945,7 @@ mod de {
 			where
 				E: serde::de::Error,
 			{
-				Parser::new(
-					Syntax::Typescript(Default::default()),
-					StringInput::new(v, BytePos::DUMMY, BytePos::DUMMY),
-					None,
-				)
-				.parse_expr()
-				.map_err(|err| {
-					let kind = err.kind().msg();
-					HANDLER.with(|handler| err.into_diagnostic(handler).emit());
-					E::custom(kind)
-				})
-				.map(Some)
+				parse_expr(v).map(Some)
 			}
 			fn visit_some<D>(self, de: D) -> Result<Self::Value, D::Error>
 			where
@@ -597,6 +1963,49 @@ mod de {
 		de.deserialize_option(ExprVisitor)
 	}
 
+	pub fn parse_expr<E>(v: &str) -> Result<Box<Expr>, E>
+	where
+		E: serde::de::Error,
+	{
+		Parser::new(
+			Syntax::Typescript(Default::default()),
+			StringInput::new(v, BytePos::DUMMY, BytePos::DUMMY),
+			None,
+		)
+		.parse_expr()
+		.map_err(|err| {
+			let kind = err.kind().msg();
+			with_handler(|handler| err.into_diagnostic(handler).emit());
+			E::custom(kind)
+		})
+	}
+
+	pub fn fold_static<'de, D>(de: D) -> Result<FoldStatic, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		struct FoldStaticVisitor;
+		impl<'de> Visitor<'de> for FoldStaticVisitor {
+			type Value = FoldStatic;
+			fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+				formatter.write_str("a boolean or an expression")
+			}
+			fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
+			where
+				E: serde::de::Error,
+			{
+				Ok(if v { FoldStatic::Literal } else { FoldStatic::Off })
+			}
+			fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+			where
+				E: serde::de::Error,
+			{
+				parse_expr(v).map(FoldStatic::Wrapper)
+			}
+		}
+		de.deserialize_any(FoldStaticVisitor)
+	}
+
 	pub fn ident<'de, D>(de: D) -> Result<Ident, D::Error>
 	where
 		D: Deserializer<'de>,
@@ -618,6 +2027,30 @@ mod de {
 		de.deserialize_str(IdentVisitor)
 	}
 
+	pub fn templates<'de, D>(de: D) -> Result<Vec<(String, Box<Expr>)>, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		struct TemplatesVisitor;
+		impl<'de> Visitor<'de> for TemplatesVisitor {
+			type Value = Vec<(String, Box<Expr>)>;
+			fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+				formatter.write_str("a map of tag names or namespaces to expressions")
+			}
+			fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+			where
+				A: MapAccess<'de>,
+			{
+				let mut templates = vec![];
+				while let Some((key, value)) = map.next_entry::<String, String>()? {
+					templates.push((key, parse_expr(&value)?));
+				}
+				Ok(templates)
+			}
+		}
+		de.deserialize_map(TemplatesVisitor)
+	}
+
 	pub fn str<'de, D>(de: D) -> Result<Option<Str>, D::Error>
 	where
 		D: Deserializer<'de>,
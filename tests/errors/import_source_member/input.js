@@ -0,0 +1 @@
+<div class="a" />;
@@ -0,0 +1,4 @@
+<div>
+  <input dangerouslySetInnerHTML={{ __html: "<b>x</b>" }} />
+  <img innerHTML={markup} />
+</div>;
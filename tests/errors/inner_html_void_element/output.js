@@ -0,0 +1 @@
+String.raw`<div><input><img></div>`;
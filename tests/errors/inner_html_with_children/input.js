@@ -0,0 +1,3 @@
+<div dangerouslySetInnerHTML={{ __html: "<b>x</b>" }}>
+  <span>ignored</span>
+</div>;
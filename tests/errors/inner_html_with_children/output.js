@@ -0,0 +1 @@
+String.raw`<div><b>x</b></div>`;
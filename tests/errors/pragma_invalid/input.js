@@ -0,0 +1,2 @@
+/* @jsxStatic maybe */
+const el = <div />;
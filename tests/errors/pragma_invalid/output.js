@@ -0,0 +1 @@
+/* @jsxStatic maybe */ const el = String.raw`<div></div>`;
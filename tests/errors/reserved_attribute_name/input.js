@@ -0,0 +1 @@
+<div $$child={child} {...{ $$spread: spread, "$$attrs": attrs }}></div>;
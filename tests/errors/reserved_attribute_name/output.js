@@ -0,0 +1,10 @@
+String.raw`<div ${{
+    $$spread: {
+        $$spread: spread,
+        "$$attrs": attrs
+    }
+}} ${{
+    $$attrs: {
+        "$$child": child
+    }
+}}></div>`;
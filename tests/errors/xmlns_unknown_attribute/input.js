@@ -0,0 +1,3 @@
+<svg>
+  <use xlink:hreff="#star" foo:bar="baz" />
+</svg>;
@@ -0,0 +1 @@
+String.raw`<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink"><use xlink:hreff="#star" foo:bar="baz"></use></svg>`;
@@ -1,36 +1,124 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use swc_core::common::{chain, Mark};
-use swc_core::ecma::parser::{EsConfig, Syntax};
+use swc_core::ecma::parser::{EsConfig, Syntax, TsConfig};
 use swc_core::ecma::transforms::base::resolver;
-use swc_core::ecma::transforms::testing::test_fixture;
+use swc_core::ecma::transforms::testing::{test_fixture, FixtureTestConfig};
 use swc_core::ecma::visit::as_folder;
 use swc_plugin_static_jsx::TransformVisitor;
 use testing::fixture;
 
-fn syntax() -> Syntax {
-	Syntax::Es(EsConfig {
-		jsx: true,
-		..Default::default()
-	})
+fn syntax(input: &Path) -> Syntax {
+	match input.extension().and_then(|ext| ext.to_str()) {
+		Some("tsx") => Syntax::Typescript(TsConfig {
+			tsx: true,
+			..Default::default()
+		}),
+		_ => Syntax::Es(EsConfig {
+			jsx: true,
+			..Default::default()
+		}),
+	}
 }
 
-#[fixture("tests/fixtures/**/input.js")]
-fn tests(input: PathBuf) {
-	let output = input.with_file_name("output.js");
+fn run(input: PathBuf, config: FixtureTestConfig) {
+	let output = match input.extension().and_then(|ext| ext.to_str()) {
+		Some("tsx") => input.with_file_name("output.tsx"),
+		_ => input.with_file_name("output.js"),
+	};
 
 	test_fixture(
-		syntax(),
-		&|_| {
-			let visitor = if let Ok(file) = std::fs::read(input.with_file_name("config.json")) {
+		syntax(&input),
+		&|tester| {
+			let unresolved_mark = Mark::new();
+			let visitor: TransformVisitor = if let Ok(file) = std::fs::read(input.with_file_name("config.json")) {
 				serde_json::from_slice(&file).expect("Failed to read config")
 			} else {
 				TransformVisitor::default()
 			};
-			chain!(resolver(Mark::new(), Mark::new(), false), as_folder(visitor))
+			chain!(
+				resolver(unresolved_mark, Mark::new(), false),
+				as_folder(
+					visitor
+						.with_unresolved_mark(unresolved_mark)
+						.with_comments(tester.comments.clone())
+				)
+			)
 		},
 		&input,
 		&output,
-		Default::default(),
+		config,
 	);
 }
+
+#[fixture("tests/fixtures/**/input.js")]
+fn tests(input: PathBuf) {
+	run(input, Default::default());
+}
+
+#[fixture("tests/fixtures/**/input.tsx")]
+fn tests_tsx(input: PathBuf) {
+	run(input, Default::default());
+}
+
+#[fixture("tests/errors/**/input.js")]
+fn errors(input: PathBuf) {
+	run(
+		input,
+		FixtureTestConfig {
+			allow_error: true,
+			..Default::default()
+		},
+	);
+}
+
+#[fixture("tests/sourcemaps/**/input.js")]
+fn sourcemaps(input: PathBuf) {
+	run(
+		input,
+		FixtureTestConfig {
+			sourcemap: true,
+			..Default::default()
+		},
+	);
+}
+
+/// Checks that the generated template and holes map back to the JSX they came from, so that a regenerated
+/// `output.map` cannot hide a regression.
+#[test]
+fn sourcemap_segments() {
+	let dir = Path::new("tests/sourcemaps/element");
+	let input = std::fs::read_to_string(dir.join("input.js")).unwrap();
+	let output = std::fs::read_to_string(dir.join("output.js")).unwrap();
+	let map = sourcemap::SourceMap::from_slice(&std::fs::read(dir.join("output.map")).unwrap()).unwrap();
+
+	// The 0-based line and column of `needle` in `text`, skipping `skip` bytes into the match.
+	let position = |text: &str, needle: &str, skip: usize| {
+		let offset = text.find(needle).unwrap_or_else(|| panic!("`{needle}` not found")) + skip;
+		let line = text[..offset].matches('\n').count() as u32;
+		let col = (offset - text[..offset].rfind('\n').map_or(0, |idx| idx + 1)) as u32;
+		(line, col)
+	};
+	let cases = [
+		// The opening tag at the start of the template.
+		(("`<section", 1), ("<section", 0)),
+		// A child hole expression.
+		(("$$child: title", 9), ("{title}", 1)),
+		// An event handler hole expression.
+		(("$$event: onSave", 9), ("{onSave}", 1)),
+	];
+	for ((dst, dst_skip), (src, src_skip)) in cases {
+		let (line, col) = position(&output, dst, dst_skip);
+		let token = map.lookup_token(line, col).unwrap();
+		assert_eq!(
+			(token.get_dst_line(), token.get_dst_col()),
+			(line, col),
+			"no mapping at `{dst}`"
+		);
+		assert_eq!(
+			(token.get_src_line(), token.get_src_col()),
+			position(&input, src, src_skip),
+			"`{dst}` is not mapped to `{src}`"
+		);
+	}
+}
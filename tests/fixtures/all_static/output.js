@@ -1 +1 @@
-String.raw`<div foo="1" bar="2" />`
\ No newline at end of file
+String.raw`<div foo="1" bar="2"></div>`;
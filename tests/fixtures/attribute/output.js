@@ -1,3 +1,5 @@
 String.raw`<div ${{
-    "foo": foo
-}}/>`
+    $$attrs: {
+        "foo": foo
+    }
+}}></div>`;
@@ -0,0 +1,2 @@
+<div title='say "hi"' alt="fish & chips" data-cmp="1 < 2" />;
+<div foo={foo} title='say "hi"' alt="fish & chips" />;
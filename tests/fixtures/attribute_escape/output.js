@@ -0,0 +1,6 @@
+String.raw`<div title="say &quot;hi&quot;" alt="fish &amp; chips" data-cmp="1 &lt; 2"></div>`;
+String.raw`<div title="say &quot;hi&quot;" alt="fish &amp; chips" ${{
+    $$attrs: {
+        "foo": foo
+    }
+}}></div>`;
@@ -0,0 +1 @@
+<input checked={true} disabled={false} title={null} alt={undefined} value={void 0} name="x" />
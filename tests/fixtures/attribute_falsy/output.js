@@ -0,0 +1 @@
+String.raw`<input checked name="x">`;
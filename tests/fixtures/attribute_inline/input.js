@@ -0,0 +1,3 @@
+<div id="main" class={cls} {...spread} title={title} hidden>
+  <input type="text" value={value} disabled={disabled} />
+</div>
@@ -0,0 +1,3 @@
+String.raw`<div id="main" class="${cls}" ${{
+    $$spread: spread
+}} title="${title}" hidden><input type="text" value="${value}" disabled="${disabled}"></div>`;
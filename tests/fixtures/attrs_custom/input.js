@@ -0,0 +1 @@
+<div title={title} $$attrs={value}></div>;
@@ -0,0 +1,6 @@
+String.raw`<div ${{
+    props: {
+        "title": title,
+        "$$attrs": value
+    }
+}}></div>`;
@@ -0,0 +1,8 @@
+<div>
+  {false}
+  {null}
+  {undefined}
+  {void 0}
+  {true}
+  text
+</div>
@@ -0,0 +1 @@
+String.raw`<div>text</div>`;
@@ -0,0 +1,7 @@
+<div>
+  <script>{`if (a < b && c > d) {}`}</script>
+  <style>{"a > b { color: red }"}</style>
+  <p>{"a < b && c > d"}</p>
+  <script>{"</script><b>x</b>"}{`</SCRIPT >`}{"</scripts </b>"}</script>
+  <style>{"</Style><b>x</b></script>"}</style>
+</div>;
@@ -0,0 +1 @@
+`<div><script>if (a < b && c > d) {}</script><style>a > b { color: red }</style><p>a &lt; b &amp;&amp; c &gt; d</p><script><\\/script><b>x</b><\\/SCRIPT ><\\/scripts </b></script><style><\\/Style><b>x</b></script></style></div>`;
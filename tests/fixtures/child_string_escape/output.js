@@ -0,0 +1 @@
+String.raw`<p>Tom &amp; Jerry &lt;3&lt;b&gt;bold&lt;/b&gt;</p>`;
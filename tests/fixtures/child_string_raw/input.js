@@ -0,0 +1,4 @@
+<p>
+  {"Tom & Jerry <3"}
+  {`<b>bold</b>`}
+</p>
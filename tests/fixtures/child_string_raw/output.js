@@ -0,0 +1 @@
+String.raw`<p>Tom & Jerry <3<b>bold</b></p>`;
@@ -0,0 +1,3 @@
+<p>
+  &lt;b&gt;x&lt;/b&gt; &amp;amp; Tom &amp; Jerry
+</p>;
@@ -0,0 +1 @@
+String.raw`<p>&lt;b&gt;x&lt;/b&gt; &amp;amp; Tom &amp; Jerry</p>`;
@@ -0,0 +1,5 @@
+<div class="card">
+  <Foo bar={1} baz="qux" enabled {...rest}>
+    Hello <b>world</b>
+  </Foo>
+</div>
@@ -0,0 +1,10 @@
+String.raw`<div class="card">${{
+    $$component: Foo,
+    props: {
+        "bar": 1,
+        "baz": "qux",
+        "enabled": true,
+        ...rest
+    },
+    children: String.raw`Hello <b>world</b>`
+}}</div>`;
@@ -0,0 +1,4 @@
+<section>
+  <ui.Button primary />
+  <this.props.Icon />
+</section>
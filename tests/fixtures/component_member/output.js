@@ -0,0 +1,9 @@
+String.raw`<section>${{
+    $$component: ui.Button,
+    props: {
+        "primary": true
+    }
+}}${{
+    $$component: this.props.Icon,
+    props: {}
+}}</section>`;
@@ -0,0 +1,8 @@
+<ul>
+  {<Item label="first" />}
+  <Outer>
+    <Inner>
+      <li>nested</li>
+    </Inner>
+  </Outer>
+</ul>
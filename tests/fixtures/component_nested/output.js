@@ -0,0 +1,14 @@
+String.raw`<ul>${{
+    $$component: Item,
+    props: {
+        "label": "first"
+    }
+}}${{
+    $$component: Outer,
+    props: {},
+    children: String.raw`${{
+        $$component: Inner,
+        props: {},
+        children: String.raw`<li>nested</li>`
+    }}`
+}}</ul>`;
@@ -0,0 +1,3 @@
+<div>
+  <Foo key="x&quot;y" title="a &amp; b" />
+</div>;
@@ -0,0 +1,7 @@
+String.raw`<div>${{
+    $$component: Foo,
+    $$key: 'x"y',
+    props: {
+        "title": "a & b"
+    }
+}}</div>`;
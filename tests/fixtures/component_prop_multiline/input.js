@@ -0,0 +1,4 @@
+<div>
+  <Foo label="first
+    second" />
+</div>;
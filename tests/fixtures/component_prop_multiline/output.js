@@ -0,0 +1,6 @@
+String.raw`<div>${{
+    $$component: Foo,
+    props: {
+        "label": "first\n    second"
+    }
+}}</div>`;
@@ -0,0 +1,20 @@
+const GAP = 4;
+const PREFIX = "icon";
+
+<div class={"card " + "card--" + "large"} width={GAP * 2 + 1} tabIndex={-1} data-zero={-0} hidden={!0} data-gap={`w-${GAP}`} style={{ marginTop: GAP / 2 }}>
+  <i class={PREFIX + "-" + "star"} title={void 0} aria-hidden={GAP > 2 && "true"} />
+  {1 + 2}
+  {"a" + "b" + GAP}
+  {null ?? "fallback"}
+  {0 || undefined}
+  {GAP ? "yes" : "no"}
+  {`${PREFIX}s`}
+  {"10" < "9" ? "str" : "num"}
+  {"10" < 9 ? "mixed" : "num"}
+  {1 / 0}
+  {-0}
+  {1e-7}
+  {"n" + 1e21}
+  {`${0.1 + 0.2}`}
+  {count + 1}
+</div>;
@@ -0,0 +1,7 @@
+const GAP = 4;
+const PREFIX = "icon";
+String.raw`<div class="card card--large" width="9" tabIndex="-1" data-zero="0" hidden data-gap="w-4" style="margin-top:2px"><i class="icon-star" aria-hidden="true"></i>3ab4fallbackyesiconsstr${{
+    $$child: "10" < 9 ? "mixed" : "num"
+}}Infinity01e-7n1e+210.30000000000000004${{
+    $$child: count + 1
+}}</div>`;
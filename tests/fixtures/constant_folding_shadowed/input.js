@@ -0,0 +1,3 @@
+function render(undefined) {
+  return <div title={undefined}>{undefined}</div>;
+}
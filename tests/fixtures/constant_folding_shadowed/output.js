@@ -0,0 +1,9 @@
+function render(undefined) {
+    return String.raw`<div ${{
+        $$attrs: {
+            "title": undefined
+        }
+    }}>${{
+        $$child: undefined
+    }}</div>`;
+}
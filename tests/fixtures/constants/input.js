@@ -0,0 +1,10 @@
+const BUTTON_CLASS = "btn";
+const SIZE = 16;
+const LABEL = `Save & exit`;
+const STYLE_COLOR = "red";
+let mutable = "no";
+const computed = getLabel();
+
+<button class={BUTTON_CLASS} width={SIZE} style={{ color: STYLE_COLOR }} {...{ title: LABEL }} data-mutable={mutable} data-computed={computed}>
+  {LABEL}
+</button>;
@@ -0,0 +1,12 @@
+const BUTTON_CLASS = "btn";
+const SIZE = 16;
+const LABEL = `Save & exit`;
+const STYLE_COLOR = "red";
+let mutable = "no";
+const computed = getLabel();
+String.raw`<button class="btn" width="16" style="color:red" title="Save &amp; exit" ${{
+    $$attrs: {
+        "data-mutable": mutable,
+        "data-computed": computed
+    }
+}}>Save &amp; exit</button>`;
@@ -0,0 +1,17 @@
+const NAME = "outer";
+
+function Component() {
+  const NAME = "inner";
+  const ID = 1;
+  return <div id={ID} data-name={NAME} />;
+}
+
+function Shadowed(NAME) {
+  return <div data-name={NAME} />;
+}
+
+function Reassigned() {
+  let VALUE = "a";
+  VALUE = "b";
+  return <div data-value={VALUE} data-outer={NAME} />;
+}
@@ -0,0 +1,22 @@
+const NAME = "outer";
+function Component() {
+    const NAME = "inner";
+    const ID = 1;
+    return String.raw`<div id="1" data-name="inner"></div>`;
+}
+function Shadowed(NAME) {
+    return String.raw`<div ${{
+        $$attrs: {
+            "data-name": NAME
+        }
+    }}></div>`;
+}
+function Reassigned() {
+    let VALUE = "a";
+    VALUE = "b";
+    return String.raw`<div data-outer="outer" ${{
+        $$attrs: {
+            "data-value": VALUE
+        }
+    }}></div>`;
+}
@@ -0,0 +1,6 @@
+<div>
+  <button onClick={() => save()} onMouseEnter={hover} onclick="legacy()" one={1} title={title}>
+    Save
+  </button>
+  <Button onClick={save} />
+</div>;
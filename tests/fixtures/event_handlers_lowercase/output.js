@@ -0,0 +1,16 @@
+String.raw`<div><button ${{
+    $$event: ()=>save(),
+    name: "click"
+}} ${{
+    $$event: hover,
+    name: "mouseenter"
+}} onclick="legacy()" one="1" ${{
+    $$attrs: {
+        "title": title
+    }
+}}>Save</button>${{
+    $$component: Button,
+    props: {
+        "onClick": save
+    }
+}}</div>`;
@@ -0,0 +1 @@
+<button on:click={save} onClick={legacy}>Save</button>;
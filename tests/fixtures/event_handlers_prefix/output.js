@@ -0,0 +1,8 @@
+String.raw`<button ${{
+    listener: save,
+    name: "click"
+}} ${{
+    $$attrs: {
+        "onClick": legacy
+    }
+}}>Save</button>`;
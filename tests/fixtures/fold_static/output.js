@@ -0,0 +1,8 @@
+function MyComponent() {
+    return '<!DOCTYPE html>' + "<div>Hello there!</div>";
+}
+String.raw`<div ${{
+    $$attrs: {
+        "class": cls
+    }
+}}></div>`;
@@ -0,0 +1,4 @@
+function MyComponent() {
+    return '<!DOCTYPE html>' + <div>Hello there!</div>
+}
+<div class={cls} />;
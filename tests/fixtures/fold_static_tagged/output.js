@@ -0,0 +1,8 @@
+function MyComponent() {
+    return '<!DOCTYPE html>' + html`<div>Hello there!</div>`;
+}
+html`<div ${{
+    $$attrs: {
+        "class": cls
+    }
+}}></div>`;
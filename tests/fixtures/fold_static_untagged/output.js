@@ -0,0 +1,8 @@
+function MyComponent() {
+    return '<!DOCTYPE html>' + "<div>Hello there!</div>";
+}
+`<div ${{
+    $$attrs: {
+        "class": cls
+    }
+}}></div>`;
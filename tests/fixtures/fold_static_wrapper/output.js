@@ -0,0 +1,8 @@
+function MyComponent() {
+    return '<!DOCTYPE html>' + unsafeStatic("<div>Hello there!</div>");
+}
+html`<div ${{
+    $$attrs: {
+        "class": cls
+    }
+}}></div>`;
@@ -0,0 +1,16 @@
+import { render } from "runtime";
+
+function Row({ label }) {
+  return <li class="row">{label}</li>;
+}
+
+function Other({ text }) {
+  return <li class="row">{text}</li>;
+}
+
+const separator = <hr />;
+const again = <hr />;
+
+function List({ items }) {
+  return <ul><Row label="x"><b>bold {items.length}</b></Row></ul>;
+}
@@ -0,0 +1,25 @@
+import { render } from "runtime";
+const _tmpl$ = ((s)=>s)`<li class="row">${0}</li>`, _tmpl$2 = String.raw`<hr>`, _tmpl$3 = ((s)=>s)`<b>bold ${0}</b>`, _tmpl$4 = ((s)=>s)`<ul>${0}</ul>`;
+function Row({ label }) {
+    return String.raw(_tmpl$, {
+        $$child: label
+    });
+}
+function Other({ text }) {
+    return String.raw(_tmpl$, {
+        $$child: text
+    });
+}
+const separator = _tmpl$2;
+const again = _tmpl$2;
+function List({ items }) {
+    return String.raw(_tmpl$4, {
+        $$component: Row,
+        props: {
+            "label": "x"
+        },
+        children: String.raw(_tmpl$3, {
+            $$child: items.length
+        })
+    });
+}
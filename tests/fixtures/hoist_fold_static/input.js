@@ -0,0 +1,2 @@
+const separator = <hr />;
+const label = <span>{text}</span>;
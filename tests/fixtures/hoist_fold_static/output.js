@@ -0,0 +1,5 @@
+const _tmpl$ = ((s)=>s)`<span>${0}</span>`;
+const separator = "<hr>";
+const label = String.raw(_tmpl$, {
+    $$child: text
+});
@@ -0,0 +1,5 @@
+import { lib } from "lib";
+
+const ui = lib.create();
+const separator = <hr />;
+const label = <span>{text}</span>;
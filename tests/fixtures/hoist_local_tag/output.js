@@ -0,0 +1,7 @@
+import { lib } from "lib";
+const _tmpl$ = ((s)=>s)`<hr>`, _tmpl$2 = ((s)=>s)`<span>${0}</span>`;
+const ui = lib.create();
+const separator = ui.html(_tmpl$);
+const label = ui.html(_tmpl$2, {
+    $$child: text
+});
@@ -0,0 +1,11 @@
+const a = (
+  <div key={id} class="card" title={title} {...rest} onClick={select}>
+    {label}
+    <Icon name="star" />
+    {...extra}
+  </div>
+);
+
+const b = <p title={other}>{text}</p>;
+
+const c = <p class="static">no holes</p>;
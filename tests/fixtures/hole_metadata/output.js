@@ -0,0 +1,60 @@
+const _holes$ = [
+    [
+        "key"
+    ],
+    [
+        "spread"
+    ],
+    [
+        "event",
+        "Click"
+    ],
+    [
+        "attr"
+    ],
+    [
+        "child"
+    ],
+    [
+        "component"
+    ],
+    [
+        "children"
+    ]
+], _holes$2 = [
+    [
+        "attr"
+    ],
+    [
+        "child"
+    ]
+];
+const a = String.raw`${_holes$}<div ${{
+    $$key: id
+}} class="card" ${{
+    $$spread: rest
+}} ${{
+    $$event: select,
+    name: "Click"
+}} ${{
+    $$attrs: {
+        "title": title
+    }
+}}>${{
+    $$child: label
+}}${{
+    $$component: Icon,
+    props: {
+        "name": "star"
+    }
+}}${{
+    $$children: extra
+}}</div>`;
+const b = String.raw`${_holes$2}<p ${{
+    $$attrs: {
+        "title": other
+    }
+}}>${{
+    $$child: text
+}}</p>`;
+const c = String.raw`<p class="static">no holes</p>`;
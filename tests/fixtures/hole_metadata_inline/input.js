@@ -0,0 +1,2 @@
+const a = <div value={value} dangerouslySetInnerHTML={{ __html: html }} />;
+const b = <div value={value} dangerouslySetInnerHTML={{ __html: html }} />;
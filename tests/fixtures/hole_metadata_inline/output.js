@@ -0,0 +1,15 @@
+const _holes$ = [
+    [
+        "attr",
+        "value"
+    ],
+    [
+        "html"
+    ]
+], _tmpl$ = ((s)=>s)`${0}<div value="${0}">${0}</div>`;
+const a = String.raw(_tmpl$, _holes$, value, {
+    $$html: html
+});
+const b = String.raw(_tmpl$, _holes$, value, {
+    $$html: html
+});
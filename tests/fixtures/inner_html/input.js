@@ -0,0 +1,10 @@
+const ICON = "<svg></svg>";
+
+<section>
+  <div class="static" dangerouslySetInnerHTML={{ __html: "<b>x</b>" }} />
+  <div dangerouslySetInnerHTML={{ __html: ICON + "<i>y</i>" }}></div>
+  <div dangerouslySetInnerHTML={{ __html: markdown(text) }} />
+  <div dangerouslySetInnerHTML={props.content} />
+  <span innerHTML={html} />
+  <span innerHTML={null} />
+</section>;
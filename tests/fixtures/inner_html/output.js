@@ -0,0 +1,8 @@
+const ICON = "<svg></svg>";
+String.raw`<section><div class="static"><b>x</b></div><div><svg></svg><i>y</i></div><div>${{
+    $$html: markdown(text)
+}}</div><div>${{
+    $$html: props.content.__html
+}}</div><span>${{
+    $$html: html
+}}</span><span></span></section>`;
@@ -0,0 +1,9 @@
+<ul>
+  {items.map((item) => (
+    <li key={item.id} ref={item.ref} class="item" title={item.title}>
+      {item.label}
+    </li>
+  ))}
+  <li key="static" {...{ ref: listRef, key: 1, id: "last" }} />
+  <Item key={id} ref={itemRef} label="x" />
+</ul>;
@@ -0,0 +1,26 @@
+String.raw`<ul>${{
+    $$child: items.map((item)=>String.raw`<li ${{
+            $$key: item.id
+        }} ${{
+            $$ref: item.ref
+        }} class="item" ${{
+            $$attrs: {
+                "title": item.title
+            }
+        }}>${{
+            $$child: item.label
+        }}</li>`)
+}}<li ${{
+    $$key: "static"
+}} id="last" ${{
+    $$ref: listRef
+}} ${{
+    $$key: 1
+}}></li>${{
+    $$component: Item,
+    $$key: id,
+    $$ref: itemRef,
+    props: {
+        "label": "x"
+    }
+}}</ul>`;
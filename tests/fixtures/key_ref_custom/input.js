@@ -0,0 +1 @@
+<div key="a" ref={el}></div>;
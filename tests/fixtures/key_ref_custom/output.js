@@ -0,0 +1,5 @@
+String.raw`<div ${{
+    k: "a"
+}} ${{
+    r: el
+}}></div>`;
@@ -1,5 +1,5 @@
 String.raw`<div foo="bar" baz ${{
     $$spread: spread
-}} std::string="value">The quick brown fox jumps over the<strong>lazy</strong>dog.<script>alert("You've been pwned!")</script> ${{
+}} std::string="value">The quick brown fox jumps over the<strong>lazy</strong>dog.&lt;script&gt;alert("You've been pwned!")&lt;/script&gt;${{
     $$children: children
-}} </div>`;
+}}</div>`;
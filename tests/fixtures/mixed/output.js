@@ -1 +1 @@
-String.raw`<div foo="1" bar="2" />`
+String.raw`<div foo="1" bar="2"></div>`;
@@ -0,0 +1,8 @@
+<div
+  icon={<svg />}
+  fallback=<span>loading</span>
+  content=<>text</>
+  title={cond ? <b>yes</b> : <i>no</i>}
+  {...{ slot: <p>{slot}</p> }}
+  {...{ ...rest, footer: <footer /> }}
+/>
@@ -0,0 +1,19 @@
+String.raw`<div ${{
+    $$spread: {
+        slot: String.raw`<p>${{
+            $$child: slot
+        }}</p>`
+    }
+}} ${{
+    $$spread: {
+        ...rest,
+        footer: String.raw`<footer></footer>`
+    }
+}} ${{
+    $$attrs: {
+        "icon": String.raw`<svg></svg>`,
+        "fallback": String.raw`<span>loading</span>`,
+        "content": String.raw`text`,
+        "title": cond ? String.raw`<b>yes</b>` : String.raw`<i>no</i>`
+    }
+}}></div>`;
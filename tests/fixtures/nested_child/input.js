@@ -0,0 +1,5 @@
+<ul>
+  {cond && <li class="empty">Nothing here</li>}
+  {items.map((item) => <li>{item}</li>)}
+  {...rows.map((row) => <tr>{row}</tr>)}
+</ul>
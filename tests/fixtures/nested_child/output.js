@@ -0,0 +1,11 @@
+String.raw`<ul>${{
+    $$child: cond && String.raw`<li class="empty">Nothing here</li>`
+}}${{
+    $$child: items.map((item)=>String.raw`<li>${{
+            $$child: item
+        }}</li>`)
+}}${{
+    $$children: rows.map((row)=>String.raw`<tr>${{
+            $$child: row
+        }}</tr>`)
+}}</ul>`;
@@ -0,0 +1,3 @@
+<div>
+  <Foo icon={<img src="icon.png" />} {...{ render: () => <span>render</span> }} />
+</div>
@@ -0,0 +1,9 @@
+String.raw`<div>${{
+    $$component: Foo,
+    props: {
+        "icon": String.raw`<img src="icon.png">`,
+        ...{
+            render: ()=>String.raw`<span>render</span>`
+        }
+    }
+}}</div>`;
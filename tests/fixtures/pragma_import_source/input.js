@@ -0,0 +1,7 @@
+/**
+ * @jsxTemplate html
+ * @jsxTemplateImportSource lit
+ */
+import { render } from "lit";
+
+render(<p class="greeting">{name}</p>, document.body);
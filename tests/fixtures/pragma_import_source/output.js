@@ -0,0 +1,8 @@
+/**
+ * @jsxTemplate html
+ * @jsxTemplateImportSource lit
+ */ import { html as _html } from "lit";
+import { render } from "lit";
+render(_html`<p class="greeting">${{
+    $$child: name
+}}</p>`, document.body);
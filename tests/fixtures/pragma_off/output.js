@@ -0,0 +1,2 @@
+// @jsxStatic off
+const el = <div class="kept"/>;
@@ -0,0 +1,2 @@
+/** @jsxTemplate svg */
+const icon = <path d="M0 0" />;
@@ -0,0 +1 @@
+/** @jsxTemplate svg */ const icon = svg`<path d="M0 0"></path>`;
@@ -0,0 +1,3 @@
+<label className="field" htmlFor="name" tabIndex={tabIndex} {...{ readOnly: true, autoComplete: "off", maxLength }}>
+  <svg strokeWidth={2} xlinkHref="#icon" viewBox="0 0 10 10" />
+</label>
@@ -0,0 +1,9 @@
+String.raw`<label class="field" for="name" readonly autocomplete="off" ${{
+    $$spread: {
+        maxLength
+    }
+}} ${{
+    $$attrs: {
+        "tabindex": tabIndex
+    }
+}}><svg stroke-width="2" xlink:href="#icon" viewBox="0 0 10 10"></svg></label>`;
@@ -1,3 +1,2 @@
 import { foo as _foo } from "bar";
-
-_foo`<div />`
+_foo`<div></div>`;
@@ -0,0 +1,5 @@
+<div>
+  <img src="foo" />
+  <br />
+  <span class="empty" />
+</div>
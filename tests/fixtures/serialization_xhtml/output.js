@@ -0,0 +1 @@
+String.raw`<div><img src="foo" /><br /><span class="empty"></span></div>`;
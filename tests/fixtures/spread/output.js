@@ -1 +1,3 @@
-String.raw`<div ${{$$spread: foo}} />`
+String.raw`<div ${{
+    $$spread: foo
+}}></div>`;
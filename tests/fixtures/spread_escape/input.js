@@ -0,0 +1 @@
+<div {...{title: "say \"hi\"", alt: `fish & chips`, "data-cmp": '1 < 2', "data-num": 2, "data-big": 10n}} />
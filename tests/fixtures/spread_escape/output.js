@@ -0,0 +1 @@
+String.raw`<div title="say &quot;hi&quot;" alt="fish &amp; chips" data-cmp="1 &lt; 2" data-num="2" data-big="10"></div>`;
@@ -0,0 +1 @@
+<div {...{ hidden: false, title: null, alt: undefined, value: void 0, open: true, rest }} />
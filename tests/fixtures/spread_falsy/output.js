@@ -0,0 +1,5 @@
+String.raw`<div open ${{
+    $$spread: {
+        rest
+    }
+}}></div>`;
@@ -0,0 +1,3 @@
+<div {...{ id: "a", data: { title: "x", key: k }, render: () => ({ a: 1 }), ...{ role: "row", key: rowKey } }} />;
+<div {...{ style: { color: "red" } }} />;
+<div {...wrap({ title: "x" })} />;
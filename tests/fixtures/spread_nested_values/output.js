@@ -0,0 +1,25 @@
+String.raw`<div id="a" role="row" ${{
+    $$key: rowKey
+}} ${{
+    $$spread: {
+        data: {
+            title: "x",
+            key: k
+        },
+        render: ()=>({
+                a: 1
+            })
+    }
+}}></div>`;
+String.raw`<div ${{
+    $$spread: {
+        style: {
+            color: "red"
+        }
+    }
+}}></div>`;
+String.raw`<div ${{
+    $$spread: wrap({
+        title: "x"
+    })
+}}></div>`;
@@ -1,6 +1,5 @@
-String.raw`<div foo="foo" cool ${{
+String.raw`<div foo="foo" bar:bar="bartrue" cool ${{
     $$spread: {
-        "bar:bar": `bar${true}`,
         baz
     }
-}} />`
+}}></div>`;
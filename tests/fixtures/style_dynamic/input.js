@@ -0,0 +1,3 @@
+<div style={{ color: "red", paddingLeft: 8, top: offset, margin: 0 }} />;
+<div style={{ ...base, color: "red" }} />;
+<div style={styles} />;
@@ -0,0 +1,21 @@
+String.raw`<div style="color:red;padding-left:8px" ${{
+    $$attrs: {
+        "style": {
+            top: offset,
+            margin: 0
+        }
+    }
+}}></div>`;
+String.raw`<div ${{
+    $$attrs: {
+        "style": {
+            ...base,
+            color: "red"
+        }
+    }
+}}></div>`;
+String.raw`<div ${{
+    $$attrs: {
+        "style": styles
+    }
+}}></div>`;
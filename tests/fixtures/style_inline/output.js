@@ -0,0 +1,19 @@
+String.raw`<div ${{
+    $$attrs: {
+        "style": {
+            color: "red",
+            paddingLeft: 8,
+            top: offset,
+            margin: 0
+        }
+    }
+}}></div>`;
+String.raw`<div ${{
+    $$attrs: {
+        "style": {
+            ...base,
+            color: "red"
+        }
+    }
+}}></div>`;
+String.raw`<div style="${styles}"></div>`;
@@ -0,0 +1,15 @@
+<div
+  style={{
+    color: "red",
+    marginTop: 4,
+    lineHeight: 1.5,
+    zIndex: 0,
+    WebkitLineClamp: 2,
+    WebkitTransition: "opacity 1s",
+    msTransform: "none",
+    "--main-color": " blue ",
+    display: null,
+    fontFamily: '"Helvetica" & sans-serif',
+  }}
+/>;
+<div style={{}} />;
@@ -0,0 +1,2 @@
+String.raw`<div style="color:red;margin-top:4px;line-height:1.5;z-index:0;-webkit-line-clamp:2;-webkit-transition:opacity 1s;-ms-transform:none;--main-color:blue;font-family:&quot;Helvetica&quot; &amp; sans-serif"></div>`;
+String.raw`<div></div>`;
@@ -0,0 +1 @@
+<p title={"C:\\dir"}>{"a\\b"} {"\\`"}</p>;
@@ -0,0 +1 @@
+html`<p title="C:\\dir">a\\b \\\`</p>`;
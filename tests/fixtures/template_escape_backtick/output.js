@@ -0,0 +1 @@
+`<p title="a\`b">\`code\`</p>`;
@@ -0,0 +1 @@
+<p title={"${alert(1)}"}>{"${alert(2)}"} costs ${price}</p>;
@@ -0,0 +1,3 @@
+`<p title="\${alert(1)}">\${alert(2)} costs $${{
+    $$child: price
+}}</p>`;
@@ -0,0 +1,23 @@
+const page = html`<main><svg viewBox="0 0 10 10">${{
+    $$child: shapes
+}}</svg></main>`;
+const icon = svg`<svg viewBox="0 0 10 10">${{
+    $$child: points.map((p)=>svg`<circle r="1" ${{
+            $$attrs: {
+                "cx": p.x,
+                "cy": p.y
+            }
+        }}></circle>`)
+}}<foreignObject>${{
+    $$child: labels.map((label)=>html`<p>${{
+            $$child: label
+        }}</p>`)
+}}</foreignObject></svg>`;
+const formula = mathml`<math>${{
+    $$child: terms.map((term)=>mathml`<mi>${{
+            $$child: term
+        }}</mi>`)
+}}</math>`;
+const tableRow = row`<tr>${{
+    $$child: cells
+}}</tr>`;
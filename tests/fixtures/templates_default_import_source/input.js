@@ -0,0 +1,2 @@
+const a = <p>{text}</p>;
+const b = <svg><circle r={r} /></svg>;
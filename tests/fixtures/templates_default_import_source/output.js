@@ -0,0 +1,9 @@
+import { html as _html, svg as _svg } from "lit";
+const a = _html`<p>${{
+    $$child: text
+}}</p>`;
+const b = _svg`<svg><circle ${{
+    $$attrs: {
+        "r": r
+    }
+}}></circle></svg>`;
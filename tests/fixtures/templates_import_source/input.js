@@ -0,0 +1,16 @@
+const page = (
+  <main>
+    <svg viewBox="0 0 10 10">{shapes}</svg>
+  </main>
+);
+
+const icon = (
+  <svg viewBox="0 0 10 10">
+    {points.map((p) => <circle cx={p.x} cy={p.y} r="1" />)}
+    <foreignObject>{labels.map((label) => <p>{label}</p>)}</foreignObject>
+  </svg>
+);
+
+
+
+
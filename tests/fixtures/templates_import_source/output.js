@@ -0,0 +1,16 @@
+import { html as _html, svg as _svg } from "lit";
+const page = _html`<main><svg viewBox="0 0 10 10">${{
+    $$child: shapes
+}}</svg></main>`;
+const icon = _svg`<svg viewBox="0 0 10 10">${{
+    $$child: points.map((p)=>_svg`<circle r="1" ${{
+            $$attrs: {
+                "cx": p.x,
+                "cy": p.y
+            }
+        }}></circle>`)
+}}<foreignObject>${{
+    $$child: labels.map((label)=>_html`<p>${{
+            $$child: label
+        }}</p>`)
+}}</foreignObject></svg>`;
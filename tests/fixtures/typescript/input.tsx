@@ -0,0 +1,13 @@
+const Row = ({ label }: { label: string }) => (
+  <tr
+    title={"row" as const}
+    class={"wide" satisfies string}
+    hidden={false as boolean}
+    style={{ color: "red" as const } satisfies object}
+    {...({ role: "row" } as const)}
+    data-label={label!}
+  >
+    <td>{label as string}</td>
+    <td>{("static" as string)!}</td>
+  </tr>
+);
@@ -0,0 +1,9 @@
+const Row = ({ label }: {
+    label: string;
+})=>String.raw`<tr title="row" class="wide" style="color:red" role="row" ${{
+        $$attrs: {
+            "data-label": label!
+        }
+    }}><td>${{
+        $$child: label as string
+    }}</td><td>static</td></tr>`;
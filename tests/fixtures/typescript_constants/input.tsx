@@ -0,0 +1,3 @@
+const size = 4 as const;
+const tone = "muted" satisfies string;
+<p class={`text-${tone}`} data-size={size * 2}>{size!}</p>;
@@ -0,0 +1,3 @@
+const size = 4 as const;
+const tone = "muted" satisfies string;
+String.raw`<p class="text-muted" data-size="8">4</p>`;
@@ -1 +1 @@
-`<div />`
+`<div></div>`;
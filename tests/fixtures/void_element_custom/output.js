@@ -0,0 +1 @@
+String.raw`<my-icon name="star"><img src="foo"><span></span>`;
@@ -0,0 +1,5 @@
+<>
+  <my-icon name="star" />
+  <img src="foo" />
+  <span />
+</>
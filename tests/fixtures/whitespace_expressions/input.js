@@ -0,0 +1,8 @@
+<p>Hello, {name}!</p>;
+<p>
+  Hello,
+  {name}
+  !
+</p>;
+<p>{first} {last}</p>;
+<p>{" padded "}{1}</p>;
@@ -0,0 +1,12 @@
+String.raw`<p>Hello, ${{
+    $$child: name
+}}!</p>`;
+String.raw`<p>Hello,${{
+    $$child: name
+}}!</p>`;
+String.raw`<p>${{
+    $$child: first
+}} ${{
+    $$child: last
+}}</p>`;
+String.raw`<p> padded 1</p>`;
@@ -0,0 +1,10 @@
+<p>The quick brown fox jumps over the <strong>lazy</strong> dog.</p>;
+<p>
+  The quick brown fox jumps over the <strong>lazy</strong>
+  dog.
+</p>;
+<p>
+  <b>a</b>
+  <i>b</i>
+</p>;
+<p><b>a</b> <i>b</i></p>;
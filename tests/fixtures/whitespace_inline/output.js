@@ -0,0 +1,4 @@
+String.raw`<p>The quick brown fox jumps over the <strong>lazy</strong> dog.</p>`;
+String.raw`<p>The quick brown fox jumps over the <strong>lazy</strong>dog.</p>`;
+String.raw`<p><b>a</b><i>b</i></p>`;
+String.raw`<p><b>a</b> <i>b</i></p>`;
@@ -0,0 +1,13 @@
+<div>   single line   </div>;
+<div>
+  leading and trailing lines
+</div>;
+<div>
+  first line
+  second   line
+
+  third line
+</div>;
+<div>	tabs	are	spaces	</div>;
+<div>
+</div>;
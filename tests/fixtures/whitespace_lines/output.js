@@ -0,0 +1,5 @@
+String.raw`<div>   single line   </div>`;
+String.raw`<div>leading and trailing lines</div>`;
+String.raw`<div>first line second   line third line</div>`;
+String.raw`<div> tabs are spaces </div>`;
+String.raw`<div></div>`;
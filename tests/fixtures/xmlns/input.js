@@ -0,0 +1,14 @@
+const icon = (
+  <div>
+    <svg viewBox="0 0 10 10">
+      <use xlink:href="#star" />
+      {shapes.map((shape) => <path d={shape} />)}
+    </svg>
+  </div>
+);
+
+const declared = <svg xmlns="http://www.w3.org/2000/svg"><circle r="1" /></svg>;
+
+const formula = <math><mi xml:lang="en">x</mi></math>;
+
+const mapped = <svg><use xlinkHref="#star" /></svg>;
@@ -0,0 +1,10 @@
+const icon = String.raw`<div><svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink" viewBox="0 0 10 10"><use xlink:href="#star"></use>${{
+    $$child: shapes.map((shape)=>String.raw`<path xmlns="http://www.w3.org/2000/svg" ${{
+            $$attrs: {
+                "d": shape
+            }
+        }}></path>`)
+}}</svg></div>`;
+const declared = String.raw`<svg xmlns="http://www.w3.org/2000/svg"><circle r="1"></circle></svg>`;
+const formula = String.raw`<math xmlns="http://www.w3.org/1998/Math/MathML"><mi xml:lang="en">x</mi></math>`;
+const mapped = String.raw`<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink"><use xlink:href="#star"></use></svg>`;
@@ -0,0 +1,12 @@
+function Card({ title, onSave, items }) {
+  return (
+    <section class="card">
+      <h2>{title}</h2>
+      <button onClick={onSave} disabled={!items.length}>
+        Save
+      </button>
+      <Badge count={items.length} />
+      {items.map((item) => <p>{item}</p>)}
+    </section>
+  );
+}
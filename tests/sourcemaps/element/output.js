@@ -0,0 +1,21 @@
+function Card({ title, onSave, items }) {
+    return String.raw`<section class="card"><h2>${{
+        $$child: title
+    }}</h2><button ${{
+        $$event: onSave,
+        name: "Click"
+    }} ${{
+        $$attrs: {
+            "disabled": !items.length
+        }
+    }}>Save</button>${{
+        $$component: Badge,
+        props: {
+            "count": items.length
+        }
+    }}${{
+        $$child: items.map((item)=>String.raw`<p>${{
+                $$child: item
+            }}</p>`)
+    }}</section>`;
+}
@@ -9,7 +9,17 @@ export interface Config {
 	 */
 	template?: string | null;
 	/**
-	 * Where to import the template function from.
+	 * Template functions for specific roots, keyed by the tag name of the root element, by the
+	 * namespace it is in (`"svg"` or `"math"`), or by `"default"`. Roots nested inside an `<svg>` or
+	 * `<math>` element are in that namespace, except for the contents of `<foreignObject>`.
+	 * Roots without a matching key use {@link template}.
+	 * @example { "svg": "svg", "math": "mathml" }
+	 * @default {}
+	 */
+	templates?: Record<string, string>;
+	/**
+	 * Where to import the template functions from. Only the template functions that are used are
+	 * imported.
 	 * If undefined, the template function needs to be defined/imported within the file.
 	 */
 	importSource?: string;
@@ -28,14 +38,168 @@ export interface Config {
 	 * @default "$$children"
 	 */
 	children?: string;
+	/**
+	 * The name of the component key to transform component elements, such as `<Foo />` or `<ui.Button />`.
+	 * @default "$$component"
+	 */
+	component?: string;
+	/**
+	 * The name of the attrs key that wraps the object of dynamic attributes, such as
+	 * `{ $$attrs: { title: value } }`. Attribute names that equal any hole key are reported as errors.
+	 * @default "$$attrs"
+	 */
+	attrs?: string;
+	/**
+	 * The name of the key for `key` attributes, which are never rendered.
+	 * @default "$$key"
+	 */
+	key?: string;
+	/**
+	 * The name of the key for `ref` attributes, which are never rendered.
+	 * @default "$$ref"
+	 */
+	ref?: string;
+	/**
+	 * The name of the key for dynamic `dangerouslySetInnerHTML` and `innerHTML` markup, which is
+	 * inserted as the children of the element without escaping.
+	 * @default "$$html"
+	 */
+	html?: string;
+	/**
+	 * The name of the key for event handler attributes such as `onClick={handler}`, which become
+	 * `{ $$event: handler, name: "Click" }` holes.
+	 * @default "$$event"
+	 */
+	event?: string;
+	/**
+	 * The prefix of event handler attributes. If it ends with a letter or digit, the event name must
+	 * start with an uppercase letter.
+	 * @default "on"
+	 */
+	eventPrefix?: string;
+	/**
+	 * If true, event names are lowercased, so `onClick` becomes `click`.
+	 * @default false
+	 */
+	lowercaseEvents?: boolean;
+	/**
+	 * If true, string and template literal children are inlined into the template as-is
+	 * instead of being HTML-escaped. Children of `script` and `style` only have closing
+	 * tags such as `</script` escaped.
+	 * @default false
+	 */
+	rawStringChildren?: boolean;
+	/**
+	 * How dynamic attributes are placed in the template.
+	 *
+	 * - `"object"`: collected into a single `{ $$attrs: { "name": value } }` interpolation after the static
+	 *   attributes.
+	 * - `"inline"`: emitted in place as `name="${value}"`, for runtimes that bind attributes by position
+	 *   such as lit-html or uhtml. A `style` object with any dynamic entry cannot be written into
+	 *   attribute text, so it is not split into static and dynamic entries. The whole object is passed in
+	 *   a `{ $$attrs: { style: { ... } } }` hole, as in `"object"` mode.
+	 * @default "object"
+	 */
+	attributeHoles?: "object" | "inline";
+	/**
+	 * If true, React prop names such as `className`, `htmlFor` or `strokeWidth` are rewritten to their
+	 * HTML or SVG attribute names. Component props are left untouched.
+	 * @default false
+	 */
+	reactAttributeNames?: boolean;
+	/**
+	 * How elements without children are closed.
+	 *
+	 * - `"html"`: `<br>` and `<div></div>`.
+	 * - `"xhtml"`: `<br />` and `<div></div>`.
+	 * - `"xml"`: `<br/>` and `<div/>`.
+	 * @default "html"
+	 */
+	serialization?: "html" | "xhtml" | "xml";
+	/**
+	 * Replaces the list of void elements, which are closed without an end tag.
+	 * @default ["area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source", "track", "wbr"]
+	 */
+	voidElements?: string[];
+	/**
+	 * Additional void elements, such as custom elements that never have children.
+	 */
+	extraVoidElements?: string[];
+	/**
+	 * What to emit for templates without any interpolations.
+	 *
+	 * - `true`: a plain string literal, if {@link template} is `String.raw` or `null`.
+	 * - A function name such as `"unsafeStatic"`: a call to that function with a string literal,
+	 *   regardless of {@link template}.
+	 * @default false
+	 */
+	foldStatic?: boolean | string;
+	/**
+	 * If true, templates are declared once at the top of the module, after the imports, and identical
+	 * templates share a declaration. Templates without holes are hoisted whole if the template function
+	 * is `String.raw` or imported. Other templates pass their hoisted strings to the template function,
+	 * so it receives the same strings array on every call.
+	 * @default false
+	 */
+	hoist?: boolean;
+	/**
+	 * If true, the outermost element of SVG and MathML markup declares its namespace with `xmlns`, and
+	 * `xmlns:xlink` if `xlink:` attributes are used, so that the markup can be used standalone.
+	 * Namespaced attributes other than the known `xlink:`, `xml:` and `xmlns:xlink` attributes inside SVG
+	 * or MathML are reported as errors.
+	 * @default false
+	 */
+	xmlns?: boolean;
+	/**
+	 * If true, tagged templates with holes start with an extra hole holding an array of
+	 * {@link HoleDescriptor}s, one for each of the other holes, so that the runtime can tell them apart
+	 * without inspecting their keys. The arrays are declared once at the top of the module.
+	 *
+	 * This shifts the arguments of the template function: `template[0]` is an empty string and the
+	 * descriptors are the first value, before the holes, as in {@link MetadataTemplateFunction}. Templates
+	 * without holes, and untagged templates, are unchanged.
+	 * @default false
+	 */
+	holeMetadata?: boolean;
 }
 
 /**
  * Should return a `JSX.Element`.
+ *
+ * Backslashes, backticks and `${` in static markup are escaped in the template literal, so the markup is
+ * in the cooked `template` strings rather than in `template.raw`.
  */
 export type TemplateFunction = (template: TemplateStringsArray, ...children: Child[]) => unknown;
 
-export type Child = { $$children: unknown } | { $$child: unknown } | { $$spread: unknown } | Record<string, unknown>;
+/**
+ * A template function called with {@link Config.holeMetadata} set: `template[0]` is `""`, and
+ * `descriptors[i]` describes `children[i]`, whose preceding string is `template[i + 1]`.
+ */
+export type MetadataTemplateFunction = (
+	template: TemplateStringsArray,
+	descriptors: HoleDescriptor[],
+	...children: Child[]
+) => unknown;
+
+export type Child =
+	| { $$children: unknown }
+	| { $$child: unknown }
+	| { $$spread: unknown }
+	| { $$key: unknown }
+	| { $$ref: unknown }
+	| { $$html: unknown }
+	| { $$event: unknown; name: string }
+	| { $$component: unknown; $$key?: unknown; $$ref?: unknown; props: Record<string, unknown>; children?: unknown }
+	| { $$attrs: Record<string, unknown> };
+
+/**
+ * Describes a hole when {@link Config.holeMetadata} is set, such as `["attr"]` for an object of
+ * dynamic attributes, `["attr", "title"]` for an inline attribute or `["event", "Click"]`.
+ */
+export type HoleDescriptor =
+	| [kind: "attr", name?: string]
+	| [kind: "event", name: string]
+	| [kind: "spread" | "key" | "ref" | "html" | "child" | "children" | "component"];
 
 declare const module: WebAssembly.Module;
 export default module;